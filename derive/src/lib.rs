@@ -0,0 +1,4014 @@
+//! Rust Derive Impl from enum
+//
+extern crate quote;
+
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::format_ident;
+use quote::quote;
+use quote::quote_spanned;
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::Comma;
+use syn::{parse_macro_input, DeriveInput, Token};
+
+/// Generates the conversions declared with `#[enum_from_variant(..)]` on the variants of an enum.
+///
+/// This crate is an implementation detail of `enum_from_variant`, which re-exports the derive and documents it.
+#[proc_macro_derive(EnumFromVariant, attributes(enum_from_variant, enum_from, from, convert))]
+pub fn derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    expand(ast).into()
+}
+
+/// Generates `From` impls sending each source into variants of several enums, from
+/// `enum_conversions! { Source => (EnumA::Variant, EnumB::Variant), .. }`, and the newtypes declared with
+/// `newtype Wrapper(Enum)`.
+///
+/// This crate is an implementation detail of `enum_from_variant`, which re-exports the macro and documents it.
+#[proc_macro]
+pub fn enum_conversions(input: TokenStream) -> TokenStream {
+    let entries = parse_macro_input!(input with Punctuated::<ConversionsEntry, Comma>::parse_terminated);
+    entries
+        .iter()
+        .map(|entry| match entry {
+            ConversionsEntry::Newtype(newtype) => expand_newtype(newtype),
+            ConversionsEntry::FanOut(fan_out) => expand_fan_out(fan_out),
+        })
+        .collect::<proc_macro2::TokenStream>()
+        .into()
+}
+
+fn expand(ast: DeriveInput) -> proc_macro2::TokenStream {
+    let enum_name = &ast.ident;
+    let variants = if let syn::Data::Enum(syn::DataEnum { variants, .. }) = ast.data {
+        variants
+    } else {
+        return syn::Error::new_spanned(enum_name, "EnumFromVariant can only be derived for enums").to_compile_error();
+    };
+
+    if !cfg!(any(feature = "std", feature = "alloc")) {
+        return syn::Error::new_spanned(
+            enum_name,
+            "enable the `std` feature of `enum_from_variant`, or `alloc` in a `no_std` crate",
+        )
+        .to_compile_error();
+    }
+
+    let enum_options = match get_enum_options(&ast.attrs) {
+        Ok(enum_options) => enum_options,
+        Err(err) => return err.to_compile_error(),
+    };
+    let batch_data = match map_enum_data_from_batch(&ast.attrs, &variants) {
+        Ok(batch_data) => batch_data,
+        Err(err) => return err.to_compile_error(),
+    };
+    let mut enum_data = match map_enum_data_from_variant(&variants) {
+        Ok(enum_data) => enum_data,
+        Err(err) => return err.to_compile_error(),
+    };
+    enum_data.extend(batch_data);
+    for entry in enum_options.table.iter() {
+        match enum_level_conversion(&variants, &entry.variant_ident, entry.source.to_owned()) {
+            Ok(conversion) => enum_data.push(conversion),
+            Err(err) => return err.to_compile_error(),
+        }
+    }
+    for box_all in enum_options.box_all.iter() {
+        for source in box_all.sources.iter() {
+            match enum_level_conversion(&variants, &box_all.variant_ident, source.to_owned()) {
+                Ok(mut conversion) => {
+                    conversion.options.boxed = true;
+                    enum_data.push(conversion);
+                },
+                Err(err) => return err.to_compile_error(),
+            }
+        }
+    }
+    // A transparent enum's sole variant converts from its field, as if it were marked `from`.
+    let transparent_field = match transparent_field(&enum_options, enum_name, &variants) {
+        Ok(transparent_field) => transparent_field,
+        Err(err) => return err.to_compile_error(),
+    };
+    if let Some((variant, field)) = transparent_field {
+        let source = syn::LitStr::new(&field.to_token_stream().to_string(), field.span());
+        match enum_level_conversion(&variants, &variant.ident, source) {
+            Ok(mut conversion) => {
+                conversion.options.from = true;
+                enum_data.push(conversion);
+            },
+            Err(err) => return err.to_compile_error(),
+        }
+    }
+    // `validate_only` generates no impls that could overlap, so it goes on to report the other errors too.
+    let conflicts = check_conflicting_sources(&enum_data).err();
+    if let Some(err) = conflicts.as_ref().filter(|_| !enum_options.validate_only) {
+        return err.to_compile_error();
+    }
+    let krate = match &enum_options.krate {
+        Some(krate) => quote!(#krate),
+        None => quote!(::enum_from_variant),
+    };
+    // The name the generated functions give the source, which `via` expressions refer to.
+    let param = enum_options.param.clone().unwrap_or_else(|| format_ident!("err"));
+    let alloc = alloc_crate();
+    // Names the fields use as types, which a source can't mean as parameters of its own.
+    let mut field_types = vec![];
+    for field in variants.iter().flat_map(|variant| variant.fields.iter()) {
+        token_idents(field.ty.to_token_stream(), &mut field_types);
+    }
+    // An enum-level bound naming a parameter that only some sources introduce, like the `U` of `U: Into<T>` for
+    // `"Wrapper<U>"`, only goes on their impls: the other impls don't declare `U`.
+    let mut bounded_generics = ast.generics.clone();
+    bounded_generics
+        .make_where_clause()
+        .predicates
+        .extend(enum_options.bounds.iter().cloned());
+    let mut source_params = vec![];
+    for m in enum_data.iter() {
+        if let syn::NestedMeta::Lit(syn::Lit::Str(str)) = &m.meta {
+            if let Ok(ty) = str.parse::<syn::Type>() {
+                source_params.extend(type_param_idents(&free_generic_params(
+                    &ty,
+                    &bounded_generics,
+                    &m.options.predicates,
+                    &field_types,
+                )));
+            }
+        }
+    }
+    let (source_bounds, enum_bounds): (Vec<_>, Vec<_>) = enum_options
+        .bounds
+        .iter()
+        .cloned()
+        .partition(|bound| names_any(bound, &source_params));
+    let mut generics = ast.generics.clone();
+    if !enum_bounds.is_empty() {
+        generics.make_where_clause().predicates.extend(enum_bounds);
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // The enum as a type, for generated items outside its impls.
+    let self_ty = quote!(#enum_name #ty_generics);
+    let mut construct_meta: Vec<_> = conflicts.iter().map(syn::Error::to_compile_error).collect();
+    // Items users name, which stay next to the enum even when the impls go into a `module`.
+    let mut declarations = vec![];
+    // Items users never name go into an anonymous `const _` block instead of the enum's module.
+    let mut helpers = vec![];
+    let mut ext_methods = vec![];
+    let mut blanket_variant: Option<&Ident> = None;
+    let mut inherent_methods = vec![];
+    let mut accessor_variants: Vec<&Ident> = vec![];
+    let mut into_variants: Vec<&Ident> = vec![];
+    let mut result_variants: Vec<&Ident> = vec![];
+    // The `From` and `TryFrom` impls into the enum, for `emit_count`.
+    let mut from_impls = 0usize;
+    // A blanket `From<E>` already covers its own field type, since every type is `Into` itself, and the standard types
+    // converting into it. Whether other sources overlap depends on their `Into` impls, which only rustc knows.
+    let blanket = enum_data
+        .iter()
+        .find(|m| matches!(&m.meta, syn::NestedMeta::Meta(syn::Meta::Path(path)) if m.options.blanket && path.is_ident("blanket")));
+    let blanket_field = blanket.and_then(|m| get_variant_unnamed_type(m.fields));
+    for m in enum_data.iter() {
+        let variant_ident = &m.variant_ident;
+        // `Enum::Variant` as users read it, without the `r#` of raw identifiers.
+        let target_type = format!("{}::{}", enum_name.unraw(), variant_ident.unraw());
+        if m.options.blanket {
+            match &m.meta {
+                syn::NestedMeta::Lit(syn::Lit::Str(str)) => construct_meta.push(quote_spanned!(
+                str.span() => ::core::compile_error!("`blanket` conversions take no source types");
+                )),
+                // Any two blankets overlap: a single source type may implement `Into` for both field types.
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("blanket") => {
+                    if let Some(first) = blanket_variant {
+                        construct_meta.push(
+                            syn::Error::new(
+                                variant_ident.span(),
+                                format!(
+                                    "only one `blanket` conversion is allowed per enum, and `{}` already has one",
+                                    first
+                                ),
+                            )
+                            .to_compile_error(),
+                        );
+                        continue;
+                    }
+                    blanket_variant = Some(variant_ident);
+                    from_impls += 1;
+                    construct_meta.push(match expand_blanket(enum_name, &generics, &param, m) {
+                        Ok(blanket) => blanket,
+                        Err(err) => err.to_compile_error(),
+                    });
+                },
+                _ => {},
+            }
+            continue;
+        }
+        let str = match &m.meta {
+            syn::NestedMeta::Lit(syn::Lit::Str(str))
+                if !m.options.result_err
+                    && !m.options.ext
+                    && str
+                        .parse::<syn::Type>()
+                        .is_ok_and(|ty| blanket_field.is_some_and(|field| std_converts_into(&ty, field))) =>
+            {
+                let message = format!(
+                    "this conversion overlaps with the `blanket` conversion into `{}`: keep only one of them, or use `ext` here",
+                    blanket.map_or(String::new(), |blanket| blanket.variant_ident.unraw().to_string())
+                );
+                construct_meta.push(syn::Error::new(str.span(), message).to_compile_error());
+                continue;
+            },
+            syn::NestedMeta::Lit(syn::Lit::Str(str)) => str,
+            syn::NestedMeta::Lit(lit) => {
+                construct_meta.push(quote_spanned!(
+                lit.span() => ::core::compile_error!("Expected the source type as a string, e.g. `\"DbError\"`");
+                ));
+                continue;
+            },
+            _ => continue,
+        };
+        if str.value().is_empty() {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("Expected this to take a `type`");
+            ));
+            continue;
+        };
+        let type_to_impl_from: syn::Type = match str.parse() {
+            Ok(ty) => ty,
+            Err(err) => {
+                construct_meta.push(err.to_compile_error());
+                continue;
+            },
+        };
+        if let syn::Type::ImplTrait(_) = ungroup_type(&type_to_impl_from) {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("`impl Trait` can't be converted from: name a concrete type, or use `blanket` to accept anything that converts into the field");
+            ));
+            continue;
+        }
+        // With `err` or `ok`, the variant is built from one side of the `Result`.
+        let result_type = type_to_impl_from.to_owned();
+        let type_to_impl_from = if m.options.result_err || m.options.result_ok {
+            match get_result_types(&type_to_impl_from) {
+                Some((_, error)) if m.options.result_err => error.to_owned(),
+                Some((ok, _)) => ok.to_owned(),
+                None => {
+                    construct_meta.push(quote_spanned!(
+                    str.span() => ::core::compile_error!("`err` and `ok` require a `Result<T, E>` source");
+                    ));
+                    continue;
+                },
+            }
+        } else if m.options.via_ref {
+            // The conversion borrows its source, which only a `via` expression can make use of.
+            if m.options.via.is_none() {
+                construct_meta.push(quote_spanned!(
+                str.span() => ::core::compile_error!("`via_ref` requires a `via` expression over the borrowed source");
+                ));
+                continue;
+            }
+            syn::parse_quote!(&#type_to_impl_from)
+        } else {
+            type_to_impl_from
+        };
+        let source_ident = get_type_ident(&type_to_impl_from);
+        let is_self =
+            matches!(ungroup_type(&type_to_impl_from), syn::Type::Path(type_path) if type_path.path.is_ident("Self"));
+        if source_ident == Some(enum_name) || is_self {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("Cannot convert an enum into itself: `From<T> for T` is already implemented by the standard library");
+            ));
+            continue;
+        }
+        // Methods don't overlap with anything, so `ext` and `err` conversions may take such a source.
+        if is_type_param_projection(&type_to_impl_from, &generics) && !m.options.ext && !m.options.result_err {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("Cannot convert from an associated type of a generic parameter: it could be this enum itself, which overlaps with `From<T> for T`. Use `ext` to generate a method instead");
+            ));
+            continue;
+        }
+        let cfg_feature = m.options.cfg_attrs();
+        let allow_deprecated = m.deprecated.then(|| quote!(#[allow(deprecated)]));
+        let track_caller = m.options.track_caller.then(|| quote!(#[track_caller]));
+        let cold = m.options.cold.then(|| quote!(#[cold]));
+        // Only a path can be an intra-doc link: a reference or tuple source is named in code spans instead.
+        let doc_link = enum_options.doc_links.then(|| {
+            let source = match ungroup_type(&type_to_impl_from) {
+                syn::Type::Path(_) => format!("[`{}`]", str.value()),
+                _ => format!("`{}`", str.value()),
+            };
+            let doc = format!("Converts {} into [`{}`].", source, target_type);
+            quote!(#[doc = #doc])
+        });
+        // An attribute with several sources still gets a single pair of accessors.
+        if m.options.accessors && !accessor_variants.contains(&variant_ident) {
+            accessor_variants.push(variant_ident);
+            let field = if let Some(field) = get_variant_unnamed_type(m.fields) {
+                field
+            } else {
+                construct_meta.push(quote_spanned!(
+                variant_ident.span() => ::core::compile_error!("`accessors` requires a tuple variant");
+                ));
+                continue;
+            };
+            let name = to_snake_case(&variant_ident.unraw().to_string());
+            let is_method = format_ident!("is_{}", name, span = variant_ident.span());
+            let as_method = format_ident!("as_{}", name, span = variant_ident.span());
+            let is_doc = format!("Whether this is a [`{}`].", target_type);
+            let as_doc = format!("The value held by a [`{}`], if this is one.", target_type);
+            let vis = enum_options.vis.as_ref().unwrap_or(&ast.vis);
+            inherent_methods.push(quote! {
+                #[doc = #is_doc]
+                #allow_deprecated
+                #vis fn #is_method(&self) -> bool {
+                    ::core::matches!(self, #enum_name::#variant_ident(..))
+                }
+
+                #[doc = #as_doc]
+                #allow_deprecated
+                #vis fn #as_method(&self) -> ::core::option::Option<&#field> {
+                    match self {
+                        #enum_name::#variant_ident(value, ..) => ::core::option::Option::Some(value),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            });
+        }
+        if m.options.into_method && !into_variants.contains(&variant_ident) {
+            into_variants.push(variant_ident);
+            let field = if let Some(field) = get_variant_unnamed_type(m.fields) {
+                field
+            } else {
+                construct_meta.push(quote_spanned!(
+                variant_ident.span() => ::core::compile_error!("`into_method` requires a tuple variant");
+                ));
+                continue;
+            };
+            let name = to_snake_case(&variant_ident.unraw().to_string());
+            let into_method = format_ident!("into_{}", name, span = variant_ident.span());
+            let doc = format!(
+                "Takes the value out of a [`{}`], or gives `self` back if this isn't one.",
+                target_type
+            );
+            let vis = enum_options.vis.as_ref().unwrap_or(&ast.vis);
+            inherent_methods.push(quote! {
+                #[doc = #doc]
+                #allow_deprecated
+                #vis fn #into_method(self) -> ::core::result::Result<#field, Self> {
+                    match self {
+                        #enum_name::#variant_ident(value, ..) => ::core::result::Result::Ok(value),
+                        #[allow(unreachable_patterns)]
+                        other => ::core::result::Result::Err(other),
+                    }
+                }
+            });
+        }
+        // A tuple source spreads over the fields of a tuple variant of the same arity, e.g. `(u32, u32)` into
+        // `Range(u32, u32)`.
+        let spread = match (ungroup_type(&type_to_impl_from), m.fields) {
+            (syn::Type::Tuple(tuple), syn::Fields::Unnamed(fields_unnamed))
+                if tuple.elems.len() > 1 && tuple.elems.len() == fields_unnamed.unnamed.len() =>
+            {
+                let indices = (0..tuple.elems.len()).map(syn::Index::from);
+                Some(quote!(#(#param.#indices),*))
+            },
+            _ => None,
+        };
+        // A tuple source over several fields of another count can be neither spread nor moved into the first field,
+        // unless that field is a tuple itself, followed by fillers.
+        if let (syn::Type::Tuple(tuple), syn::Fields::Unnamed(fields_unnamed), None) =
+            (ungroup_type(&type_to_impl_from), m.fields, &m.options.via)
+        {
+            let (elements, fields) = (tuple.elems.len(), fields_unnamed.unnamed.len());
+            let into_tuple_field = fields_unnamed
+                .unnamed
+                .first()
+                .is_some_and(|field| matches!(ungroup_type(&field.ty), syn::Type::Tuple(_)));
+            if elements > 1 && fields > 1 && elements != fields && !into_tuple_field {
+                let message = format!(
+                    "a tuple source spreads over the fields of `{}`, but has {} elements for its {} fields",
+                    variant_ident, elements, fields
+                );
+                construct_meta.push(quote_spanned!(str.span() => ::core::compile_error!(#message);));
+                continue;
+            }
+        }
+        let builds_enum = m
+            .options
+            .via
+            .as_ref()
+            .is_some_and(|via| !m.options.fallible && builds_enum(via, enum_name));
+        // A `via` producing a tuple, directly or from a closure, spreads over a tuple variant of the same arity.
+        let via_arity = m
+            .options
+            .via
+            .as_ref()
+            .filter(|_| !m.options.fallible)
+            .and_then(|via| match via {
+                syn::Expr::Closure(syn::ExprClosure { body, .. }) => tuple_arity(body),
+                via => tuple_arity(via),
+            })
+            .filter(|arity| *arity > 1 && *arity == m.fields.len());
+        let fillers = if spread.is_some() || builds_enum || via_arity.is_some() {
+            Ok(vec![])
+        } else {
+            trailing_field_fillers(m.fields)
+        };
+        let fillers = match fillers {
+            Ok(fillers) => fillers,
+            Err(err) => {
+                construct_meta.push(err.to_compile_error());
+                continue;
+            },
+        };
+        if m.options.backtrace && !matches!(m.fields, syn::Fields::Named(_)) {
+            construct_meta.push(quote_spanned!(
+            variant_ident.span() => ::core::compile_error!("`backtrace` requires a struct variant with a `backtrace` field");
+            ));
+            continue;
+        }
+        if m.options.timestamp && !matches!(m.fields, syn::Fields::Named(_)) {
+            construct_meta.push(quote_spanned!(
+            variant_ident.span() => ::core::compile_error!("`timestamp` requires a struct variant with a `when` field");
+            ));
+            continue;
+        }
+        if m.options.result && (m.options.fallible || m.options.result_ok || m.options.result_err) {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("`result` only applies to `From` conversions");
+            ));
+            continue;
+        }
+        if m.options.also_boxed && (m.options.fallible || m.options.result_ok) {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("`also_boxed` only applies to `From` conversions");
+            ));
+            continue;
+        }
+        if m.options.keep_source && (!m.options.fallible || m.options.via.is_some() || m.options.error.is_some()) {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("`keep_source` requires `try`, without `via` or `error`");
+            ));
+            continue;
+        }
+        if m.options.when.is_some() && (!m.options.fallible || m.options.via.is_some() || m.options.error.is_some()) {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("`when` requires `try`, without `via` or `error`");
+            ));
+            continue;
+        }
+        if m.options.error.is_some() && !m.options.fallible && !m.options.result_ok {
+            construct_meta.push(quote_spanned!(
+            str.span() => ::core::compile_error!("`error` only applies to `try` and `ok` conversions");
+            ));
+            continue;
+        }
+        let via = m.options.via.as_ref().map(|via| match via {
+            syn::Expr::Closure(closure) => {
+                // Closures are type-checked before the call, so their argument is given the source type up front.
+                let mut closure = closure.to_owned();
+                for input in closure.inputs.iter_mut() {
+                    if !matches!(input, syn::Pat::Type(_)) {
+                        *input = syn::Pat::Type(syn::PatType {
+                            attrs: vec![],
+                            pat: Box::new(input.to_owned()),
+                            colon_token: Default::default(),
+                            ty: Box::new(type_to_impl_from.to_owned()),
+                        });
+                    }
+                }
+                quote!((#closure)(#param))
+            },
+            _ => quote!(#via),
+        });
+        let mut stringifies = false;
+        let construct = if let (true, Some(via)) = (builds_enum, &via) {
+            via.to_owned()
+        } else if let (Some(arity), Some(via)) = (via_arity, &via) {
+            let indices = (0..arity).map(syn::Index::from);
+            quote!({
+                let fields = #via;
+                #enum_name::#variant_ident(#(fields.#indices),*)
+            })
+        } else if let Some(from_field) = &m.from_field {
+            let from_ident = &from_field.ident;
+            let others = match other_fields(m.fields, from_field, &m.options) {
+                Ok(others) => others,
+                Err(err) => {
+                    construct_meta.push(err.to_compile_error());
+                    continue;
+                },
+            };
+            quote! {
+                #enum_name::#variant_ident {
+                    #from_ident: #param,
+                    #(#others,)*
+                }
+            }
+        } else if m.fields.is_empty()
+            && matches!(ungroup_type(&type_to_impl_from), syn::Type::Tuple(tuple) if tuple.elems.is_empty())
+        {
+            match m.fields {
+                syn::Fields::Named(_) => quote!(#enum_name::#variant_ident {}),
+                syn::Fields::Unnamed(_) => quote!(#enum_name::#variant_ident()),
+                syn::Fields::Unit => quote!(#enum_name::#variant_ident),
+            }
+        } else if m.fields.is_empty() {
+            construct_meta.push(quote_spanned!(
+            variant_ident.span() => ::core::compile_error!("This variant has no field to hold the source: only `()` converts into a fieldless variant");
+            ));
+            continue;
+        } else if m.options.chain {
+            if !has_named_fields(m.fields, &["message", "source"]) {
+                construct_meta.push(quote_spanned!(
+                variant_ident.span() => ::core::compile_error!("`chain` requires a struct variant with `message` and `source` fields");
+                ));
+                continue;
+            }
+            quote! {
+                #enum_name::#variant_ident {
+                    message: #alloc::string::ToString::to_string(&#param),
+                    source: #alloc::boxed::Box::new(#param),
+                }
+            }
+        } else {
+            let value = if let Some(spread) = &spread {
+                spread.to_owned()
+            } else if m.options.keep_source {
+                // `TryInto` consumes its input, so it converts a clone and the source is handed back on failure.
+                let source_type = str.value();
+                quote! {
+                    match ::core::convert::TryInto::try_into(::core::clone::Clone::clone(&#param)) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(_) => {
+                            return ::core::result::Result::Err((#param, #krate::ConversionError {
+                                source_type: #source_type,
+                                target_type: #target_type,
+                            }))
+                        },
+                    }
+                }
+            } else if m.options.fallible && m.options.error.is_some() {
+                // A custom error type must be buildable from whatever `TryInto` fails with.
+                quote!(::core::convert::TryInto::try_into(#param)?)
+            } else if m.options.fallible {
+                let source_type = str.value();
+                quote! {
+                    ::core::convert::TryInto::try_into(#param).map_err(|_| #krate::ConversionError {
+                        source_type: #source_type,
+                        target_type: #target_type,
+                    })?
+                }
+            } else if let Some(via) = &via {
+                via.to_owned()
+            } else if let Some(wrap) = &m.options.wrap {
+                quote!(#wrap(#param))
+            } else if m.options.unwrap {
+                quote!(#param.0)
+            } else if let Some(through) = &m.options.through {
+                quote!(<#through as ::core::convert::From<#type_to_impl_from>>::from(#param))
+            } else if m.options.from {
+                quote!(#param)
+            } else if let Some(target) = &m.options.into_turbofish {
+                quote!(::core::convert::Into::<#target>::into(#param))
+            } else if m.options.boxed || m.options.boxed_dyn {
+                quote!(#alloc::boxed::Box::new(#param))
+            } else if m.options.anyhow {
+                // Named by the field's own type, so a re-exported or aliased `anyhow::Error` works too.
+                let field = source_field(m.fields).map(|field| &field.ty);
+                quote!(<#field>::new(#param))
+            } else if m.options.collect {
+                let field = source_field(m.fields).map(|field| ungroup_type(&field.ty));
+                let same_type = |ty: &syn::Type| {
+                    ungroup_type(ty).to_token_stream().to_string()
+                        == ungroup_type(&type_to_impl_from).to_token_stream().to_string()
+                };
+                match field {
+                    // A single element goes into a one-element `Vec`.
+                    Some(syn::Type::Path(type_path))
+                        if m.inner_ident.as_ref().is_some_and(|ident| ident == "Vec")
+                            && single_type_argument(type_path).is_some_and(same_type) =>
+                    {
+                        quote!(#alloc::vec![#param])
+                    },
+                    Some(field) if !same_type(field) => quote! {
+                        ::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter(#param))
+                    },
+                    _ => {
+                        construct_meta.push(quote_spanned!(
+                        variant_ident.span() => ::core::compile_error!("`collect` requires a collection field, such as a `Vec` of the source or a `HashMap` of its items");
+                        ));
+                        continue;
+                    },
+                }
+            } else if let Some(prefix) = &m.options.prefix {
+                let format = format!("{}{{}}", prefix.value().replace('{', "{{").replace('}', "}}"));
+                stringifies = true;
+                quote!(#alloc::format!(#format, #param))
+            } else if let Some(mode) = m.options.mode {
+                match mode {
+                    ValueMode::Move => quote!(#param),
+                    ValueMode::Stringify => {
+                        stringifies = true;
+                        quote!(#alloc::string::ToString::to_string(&#param))
+                    },
+                    ValueMode::DebugString => quote!(#alloc::format!("{:?}", #param)),
+                    ValueMode::Into => quote!(::core::convert::Into::into(#param)),
+                    ValueMode::ToOwned => quote!(#alloc::borrow::ToOwned::to_owned(#param)),
+                    ValueMode::Cast => {
+                        let field = source_field(m.fields).map(|field| &field.ty);
+                        quote!(#param as #field)
+                    },
+                }
+            } else if enum_options.strict {
+                construct_meta.push(quote_spanned!(
+                str.span() => ::core::compile_error!("`strict` requires an explicit `move`, `stringify`, `into` or `to_owned` for this conversion");
+                ));
+                continue;
+            } else if source_field(m.fields).is_some_and(|field| {
+                // A source of the field's own type, like `Option<Box<dyn Error>>` or `String`, is moved as is.
+                ungroup_type(&field.ty).to_token_stream().to_string()
+                    == ungroup_type(&type_to_impl_from).to_token_stream().to_string()
+            }) {
+                quote!(#param)
+            } else {
+                match get_inner_ident_type(m.fields) {
+                    InnerIdentTypes::Named => quote!(#param),
+                    InnerIdentTypes::OptionBox => quote!(::core::option::Option::Some(#alloc::boxed::Box::new(#param))),
+                    // Borrowed sources go into `Cow::Borrowed`, owned ones into `Cow::Owned`.
+                    InnerIdentTypes::Cow => match ungroup_type(&type_to_impl_from) {
+                        syn::Type::Reference(_) => quote!(#alloc::borrow::Cow::Borrowed(#param)),
+                        _ => quote!(#alloc::borrow::Cow::Owned(#param)),
+                    },
+                    _ => {
+                        stringifies = true;
+                        quote!(#alloc::string::ToString::to_string(&#param))
+                    },
+                }
+            };
+            match (m.fields, source_field(m.fields)) {
+                (syn::Fields::Named(_), Some(field)) => {
+                    let field_ident = &field.ident;
+                    let others = match other_fields(m.fields, field, &m.options) {
+                        Ok(others) => others,
+                        Err(err) => {
+                            construct_meta.push(err.to_compile_error());
+                            continue;
+                        },
+                    };
+                    quote! {
+                        #enum_name::#variant_ident {
+                            #field_ident: #value,
+                            #(#others,)*
+                        }
+                    }
+                },
+                (syn::Fields::Named(_), None) => {
+                    construct_meta.push(quote_spanned!(
+                    variant_ident.span() => ::core::compile_error!("A struct variant converts into its only field or its `source` field: mark the field to fill `#[from]`");
+                    ));
+                    continue;
+                },
+                _ => quote!(#enum_name::#variant_ident(#value #(, #fillers)*)),
+            }
+        };
+        let on_convert = m.options.on_convert.as_ref().map(|hook| quote!(#hook(&#param);));
+        // Names the missing `Display` at the attribute instead of at the `to_string` call.
+        let on_convert = if m.options.assert_display && stringifies {
+            let assert_display = quote_spanned!(str.span() => assert_display::<#type_to_impl_from>());
+            quote! {
+                fn assert_display<T: ::core::fmt::Display + ?Sized>() {}
+                #assert_display;
+                #on_convert
+            }
+        } else {
+            quote!(#on_convert)
+        };
+        let on_convert = if m.options.count {
+            let source_ident = if let Some(source_ident) = source_ident {
+                source_ident
+            } else {
+                construct_meta.push(quote_spanned!(
+                str.span() => ::core::compile_error!("`count` requires a named source type");
+                ));
+                continue;
+            };
+            let counter = format_ident!("{}_count", enum_options.method_name("from_", source_ident));
+            let doc = format!(
+                "Counts the conversions of a `{}` into a [`{}`].",
+                str.value(),
+                target_type
+            );
+            let vis = enum_options.vis.as_ref().unwrap_or(&ast.vis);
+            inherent_methods.push(quote! {
+                #[doc = #doc]
+                #cfg_feature
+                #vis fn #counter() -> &'static ::core::sync::atomic::AtomicUsize {
+                    static COUNT: ::core::sync::atomic::AtomicUsize = ::core::sync::atomic::AtomicUsize::new(0);
+                    &COUNT
+                }
+            });
+            quote! {
+                #on_convert
+                <#enum_name #ty_generics>::#counter().fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+            }
+        } else {
+            on_convert
+        };
+        if m.options.also_str {
+            if !matches!(m.fields, syn::Fields::Unnamed(_))
+                || !matches!(get_inner_ident_type(m.fields), InnerIdentTypes::String)
+            {
+                construct_meta.push(quote_spanned!(
+                variant_ident.span() => ::core::compile_error!("`also_str` requires a `String` field");
+                ));
+                continue;
+            }
+            from_impls += 1;
+            construct_meta.push(quote! {
+                #cfg_feature
+                impl #impl_generics ::core::convert::From<&str> for #enum_name #ty_generics #where_clause {
+                    #allow_deprecated
+                    fn from(#param: &str) -> Self {
+                        #enum_name::#variant_ident(#alloc::string::ToString::to_string(#param) #(, #fillers)*)
+                    }
+                }
+            });
+        }
+        if m.options.result_err {
+            let source_ident = if let Some(source_ident) = source_ident {
+                source_ident
+            } else {
+                construct_meta.push(quote_spanned!(
+                str.span() => ::core::compile_error!("`err` requires a named error type");
+                ));
+                continue;
+            };
+            let method = format_ident!("{}_result", enum_options.method_name("from_", source_ident));
+            let doc = format!(
+                "Maps the error of a `Result` into a [`{}`], keeping its `Ok` value.",
+                target_type
+            );
+            let vis = enum_options.vis.as_ref().unwrap_or(&ast.vis);
+            inherent_methods.push(quote! {
+                #[doc = #doc]
+                #cfg_feature
+                #allow_deprecated
+                #vis fn #method<__EnumFromVariantOk>(
+                    result: ::core::result::Result<__EnumFromVariantOk, #type_to_impl_from>,
+                ) -> ::core::result::Result<__EnumFromVariantOk, Self> {
+                    result.map_err(|#param| {
+                        #on_convert
+                        #construct
+                    })
+                }
+            });
+            continue;
+        }
+        if m.options.result {
+            // The helper is named after the variant, so only one of its sources can have one.
+            if result_variants.contains(&variant_ident) {
+                construct_meta.push(quote_spanned!(
+                str.span() => ::core::compile_error!("`result` can only be set on one source of a variant");
+                ));
+                continue;
+            }
+            result_variants.push(variant_ident);
+            let method = format_ident!("err_{}", to_snake_case(&variant_ident.unraw().to_string()));
+            let doc = format!(
+                "Returns a [`{}`] built from a `{}` as an `Err`.",
+                target_type,
+                str.value()
+            );
+            let vis = enum_options.vis.as_ref().unwrap_or(&ast.vis);
+            inherent_methods.push(quote! {
+                #[doc = #doc]
+                #cfg_feature
+                #allow_deprecated
+                #track_caller
+                #cold
+                #vis fn #method<__EnumFromVariantOk>(#param: #type_to_impl_from) -> ::core::result::Result<__EnumFromVariantOk, Self> {
+                    #on_convert
+                    ::core::result::Result::Err(#construct)
+                }
+            });
+        }
+        if m.options.ext {
+            let source_ident = if let Some(source_ident) = source_ident {
+                source_ident
+            } else {
+                construct_meta.push(quote_spanned!(
+                str.span() => ::core::compile_error!("`ext` requires a named source type");
+                ));
+                continue;
+            };
+            let method = enum_options.method_name("from_", source_ident);
+            let doc = format!("Converts a `{}` into a [`{}`].", str.value(), target_type);
+            ext_methods.push((
+                quote!(#[doc = #doc] #cfg_feature fn #method(#param: #type_to_impl_from) -> Self;),
+                quote! {
+                    #cfg_feature
+                    #allow_deprecated
+                    #track_caller
+                    #cold
+                    fn #method(#param: #type_to_impl_from) -> Self {
+                        #on_convert
+                        #construct
+                    }
+                },
+            ));
+            continue;
+        }
+        let predicates: Vec<_> = m.options.predicates.iter().chain(&source_bounds).cloned().collect();
+        let free_params = free_generic_params(&type_to_impl_from, &generics, &predicates, &field_types);
+        let free_idents = type_param_idents(&free_params);
+        let mut conversion_generics = generics.clone();
+        conversion_generics.params.extend(free_params);
+        let (impl_generics, _, _) = conversion_generics.split_for_impl();
+        let mut conversion_where = where_clause.cloned();
+        let predicates: Vec<_> = m
+            .options
+            .predicates
+            .iter()
+            .chain(source_bounds.iter().filter(|bound| names_any(bound, &free_idents)))
+            .cloned()
+            .collect();
+        if !predicates.is_empty() {
+            conversion_where
+                .get_or_insert_with(|| syn::parse_quote!(where))
+                .predicates
+                .extend(predicates);
+        }
+        // The impl is written by hand, next to the enum. Only `assert_impls` checks that it exists.
+        if m.options.manual {
+            if enum_options.assert_impls {
+                let conversion = if m.options.fallible || m.options.result_ok {
+                    quote!(::core::convert::TryFrom)
+                } else {
+                    quote!(::core::convert::From)
+                };
+                helpers.push(assert_impl(
+                    &cfg_feature,
+                    &impl_generics,
+                    &conversion_where,
+                    &self_ty,
+                    conversion,
+                    assert_impl_call(&type_to_impl_from, enum_name, &ty_generics, str),
+                ));
+            }
+            continue;
+        }
+        if m.options.result_ok {
+            let (error, error_binding, map_error) = match &m.options.error {
+                Some(error) => (
+                    quote!(#error),
+                    quote!(error),
+                    quote!(::core::convert::From::from(error)),
+                ),
+                None => {
+                    let source_type = str.value();
+                    (
+                        quote!(#krate::ConversionError),
+                        quote!(_),
+                        quote!(#krate::ConversionError {
+                            source_type: #source_type,
+                            target_type: #target_type,
+                        }),
+                    )
+                },
+            };
+            from_impls += 1;
+            construct_meta.push(quote! {
+                #doc_link
+                #cfg_feature
+                impl #impl_generics ::core::convert::TryFrom<#result_type> for #enum_name #ty_generics #conversion_where {
+                    type Error = #error;
+
+                    #allow_deprecated
+                    #track_caller
+                    #cold
+                    fn try_from(result: #result_type) -> ::core::result::Result<Self, Self::Error> {
+                        match result {
+                            ::core::result::Result::Ok(#param) => {
+                                #on_convert
+                                ::core::result::Result::Ok(#construct)
+                            },
+                            ::core::result::Result::Err(#error_binding) => ::core::result::Result::Err(#map_error),
+                        }
+                    }
+                }
+            });
+            continue;
+        }
+        if m.options.fallible {
+            let error = match &m.options.error {
+                Some(error) => quote!(#error),
+                None if m.options.keep_source => quote!((#type_to_impl_from, #krate::ConversionError)),
+                None => quote!(#krate::ConversionError),
+            };
+            // With `try`, a `via` expression is the whole body and returns `Result<Self, Self::Error>` itself. The typed
+            // binding reports any other type at the attribute, and lets a closure's `?` infer the error.
+            let body = match via {
+                Some(via) => quote_spanned! {str.span() =>
+                    let converted: ::core::result::Result<Self, Self::Error> = #via;
+                    converted
+                },
+                None => quote!(::core::result::Result::Ok(#construct)),
+            };
+            // A source failing `when` is rejected before it is converted, and handed back with `keep_source`.
+            let body = match &m.options.when {
+                Some(when) => {
+                    let source_type = str.value();
+                    let error = quote!(#krate::ConversionError {
+                        source_type: #source_type,
+                        target_type: #target_type,
+                    });
+                    let error = if m.options.keep_source {
+                        quote!((#param, #error))
+                    } else {
+                        error
+                    };
+                    quote! {
+                        if !(#when) {
+                            return ::core::result::Result::Err(#error);
+                        }
+                        #body
+                    }
+                },
+                None => body,
+            };
+            if enum_options.assert_impls {
+                helpers.push(assert_impl(
+                    &cfg_feature,
+                    &impl_generics,
+                    &conversion_where,
+                    &self_ty,
+                    quote!(::core::convert::TryFrom),
+                    assert_impl_call(&type_to_impl_from, enum_name, &ty_generics, str),
+                ));
+            }
+            from_impls += 1;
+            construct_meta.push(quote! {
+                #doc_link
+                #cfg_feature
+                impl #impl_generics ::core::convert::TryFrom<#type_to_impl_from> for #enum_name #ty_generics #conversion_where {
+                    type Error = #error;
+
+                    #allow_deprecated
+                    #track_caller
+                    #cold
+                    fn try_from(#param: #type_to_impl_from) -> ::core::result::Result<Self, Self::Error> {
+                        #on_convert
+                        #body
+                    }
+                }
+            });
+            continue;
+        }
+        if m.options.boxed_dyn || m.options.anyhow {
+            // Spelling the bounds out points a missing one at the attribute instead of at the unsizing coercion.
+            conversion_where
+                .get_or_insert_with(|| syn::parse_quote!(where))
+                .predicates
+                .push(syn::parse_quote_spanned!(str.span() => #type_to_impl_from: ::std::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static));
+        }
+        if enum_options.assert_impls {
+            helpers.push(assert_impl(
+                &cfg_feature,
+                &impl_generics,
+                &conversion_where,
+                &self_ty,
+                quote!(::core::convert::From),
+                assert_impl_call(&type_to_impl_from, enum_name, &ty_generics, str),
+            ));
+        }
+        from_impls += 1;
+        construct_meta.push(quote! {
+            #doc_link
+            #cfg_feature
+            impl #impl_generics ::core::convert::From<#type_to_impl_from> for #enum_name #ty_generics #conversion_where {
+                #allow_deprecated
+                #track_caller
+                #cold
+                fn from(#param: #type_to_impl_from) -> Self {
+                    #on_convert
+                    #construct
+                }
+            }
+        });
+        if m.options.also_boxed {
+            from_impls += 1;
+            construct_meta.push(quote! {
+                #cfg_feature
+                impl #impl_generics ::core::convert::From<#alloc::boxed::Box<#type_to_impl_from>> for #enum_name #ty_generics #conversion_where {
+                    #track_caller
+                    #cold
+                    fn from(#param: #alloc::boxed::Box<#type_to_impl_from>) -> Self {
+                        <#enum_name #ty_generics as ::core::convert::From<#type_to_impl_from>>::from(*#param)
+                    }
+                }
+            });
+        }
+    }
+
+    // Methods take the enum's visibility, which would be narrower inside a `module`.
+    if !inherent_methods.is_empty() {
+        declarations.push(quote! {
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #(#inherent_methods)*
+            }
+        });
+    }
+
+    if let Some(io_kind) = &enum_options.io_kind {
+        from_impls += 1;
+        construct_meta.push(match expand_io_kind(enum_name, &generics, &param, io_kind, &variants) {
+            Ok(io_kind) => io_kind,
+            Err(err) => err.to_compile_error(),
+        });
+    }
+
+    if let (true, Some((variant, field))) = (enum_options.deref, transparent_field) {
+        let variant_ident = &variant.ident;
+        construct_meta.push(quote! {
+            impl #impl_generics ::core::ops::Deref for #enum_name #ty_generics #where_clause {
+                type Target = #field;
+
+                fn deref(&self) -> &#field {
+                    match self {
+                        #enum_name::#variant_ident(inner) => inner,
+                    }
+                }
+            }
+        });
+    }
+
+    for group in enum_options.groups.iter() {
+        from_impls += 1;
+        construct_meta.push(match expand_group(enum_name, &generics, &param, group, &variants) {
+            Ok(group) => group,
+            Err(err) => err.to_compile_error(),
+        });
+    }
+
+    for flatten in enum_options.flatten.iter() {
+        from_impls += flatten.arms.len();
+        construct_meta.push(match expand_flatten(enum_name, &generics, &param, flatten, &variants) {
+            Ok(flatten) => flatten,
+            Err(err) => err.to_compile_error(),
+        });
+    }
+
+    if enum_options.display_delegate {
+        construct_meta.push(expand_display_delegate(enum_name, &generics, &variants));
+    }
+
+    for bidirectional in enum_options.bidirectional.iter() {
+        from_impls += 1;
+        construct_meta.push(
+            match expand_bidirectional(enum_name, &generics, bidirectional, &variants) {
+                Ok(bidirectional) => bidirectional,
+                Err(err) => err.to_compile_error(),
+            },
+        );
+    }
+
+    if !ext_methods.is_empty() {
+        let vis = enum_options.vis.as_ref().unwrap_or(&ast.vis);
+        let ext_trait = format_ident!("{}FromExt", enum_name);
+        let ext_trait_doc = format!(
+            "Conversions into [`{}`] that can't be expressed as `From` impls.",
+            enum_name.unraw()
+        );
+        let (signatures, methods): (Vec<_>, Vec<_>) = ext_methods.into_iter().unzip();
+        // The trait takes the enum's generics, which its sources may refer to, e.g. `T::Error`.
+        declarations.push(quote! {
+            #[doc = #ext_trait_doc]
+            #vis trait #ext_trait #generics #where_clause {
+                #(#signatures)*
+            }
+        });
+        helpers.push(quote! {
+            impl #impl_generics #ext_trait #ty_generics for #enum_name #ty_generics #where_clause {
+                #(#methods)*
+            }
+        });
+    }
+
+    if enum_options.emit_count {
+        let vis = enum_options.vis.as_ref().unwrap_or(&ast.vis);
+        declarations.push(quote! {
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                /// The number of `From` and `TryFrom` impls into this enum generated by `EnumFromVariant`.
+                #vis const GENERATED_FROM_COUNT: usize = #from_impls;
+            }
+        });
+    }
+
+    if enum_options.validate_only {
+        let errors = construct_meta.iter().filter(|tokens| is_compile_error(tokens));
+        return quote!(#(#errors)*);
+    }
+
+    let helpers = (!helpers.is_empty()).then(|| {
+        quote! {
+            const _: () = {
+                #(#helpers)*
+            };
+        }
+    });
+    let impls = match &enum_options.module {
+        Some(module) => quote! {
+            mod #module {
+                use super::*;
+
+                #(#construct_meta)*
+            }
+        },
+        None => quote!(#(#construct_meta)*),
+    };
+    let expanded = quote!(#impls #(#declarations)* #helpers);
+    if enum_options.debug {
+        eprintln!("EnumFromVariant for `{}`:\n{}", enum_name, expanded);
+    }
+    expanded
+}
+
+/// Rejects a source that two conversions claim, wherever they are declared: on variants, in `#[enum_from(..)]`, in
+/// `table` or in `box_all`. Both would implement `From` or `TryFrom` of it, which rustc only reports as overlapping
+/// impls. Copies within one variant's attributes are already dropped.
+fn check_conflicting_sources(enum_data: &[MapEnumData]) -> Result<(), syn::Error> {
+    let mut claimed: Vec<((String, bool, String), &Ident)> = vec![];
+    let mut errors: Option<syn::Error> = None;
+    for m in enum_data.iter().filter(|m| m.options.claims_source()) {
+        let syn::NestedMeta::Lit(syn::Lit::Str(str)) = &m.meta else {
+            continue;
+        };
+        // Conversions behind different `cfg`s may never be compiled together.
+        let source = (
+            normalized_source(str),
+            m.options.via_ref,
+            m.options.cfg_attrs().to_string(),
+        );
+        let message = match claimed.iter().find(|(claimed, _)| *claimed == source) {
+            Some((_, routed_to)) if *routed_to != &m.variant_ident => format!(
+                "`{}` is already converted into `{}`, so it can't also go to `{}`",
+                str.value(),
+                routed_to.unraw(),
+                m.variant_ident.unraw()
+            ),
+            Some(_) => format!(
+                "`{}` is already converted into `{}`: keep only one of its conversions",
+                str.value(),
+                m.variant_ident.unraw()
+            ),
+            None => {
+                claimed.push((source, &m.variant_ident));
+                continue;
+            },
+        };
+        let error = syn::Error::new(str.span(), message);
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Whether generated tokens are a `compile_error!` reporting a problem, rather than an item.
+fn is_compile_error(tokens: &proc_macro2::TokenStream) -> bool {
+    tokens
+        .clone()
+        .into_iter()
+        .take_while(|token| !matches!(token, proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '!'))
+        .last()
+        .is_some_and(|token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "compile_error"))
+}
+
+/// The `assert_impls` check of one conversion: a function, never called, whose `call` of `assert_impl::<Source,
+/// Enum>()` only compiles when the enum implements `conversion<Source>`. The call is spanned at the attribute, so a
+/// conversion that doesn't hold is reported there. The function is free, so `Self` in the enum's bounds is spelled out
+/// as `self_ty`.
+fn assert_impl(
+    cfg_feature: &proc_macro2::TokenStream,
+    impl_generics: &syn::ImplGenerics,
+    where_clause: &Option<syn::WhereClause>,
+    self_ty: &proc_macro2::TokenStream,
+    conversion: proc_macro2::TokenStream,
+    call: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let where_clause = replace_self(where_clause.to_token_stream(), self_ty);
+    quote! {
+        #cfg_feature
+        const _: () = {
+            fn assert_impl<__EnumFromVariantSource, __EnumFromVariantTarget: #conversion<__EnumFromVariantSource>>() {}
+            #[allow(dead_code)]
+            fn check #impl_generics () #where_clause {
+                #call;
+            }
+        };
+    }
+}
+
+/// The `call` of an `assert_impl` check, spanned at the source in the attribute. The enum's name is respanned too, as
+/// rustc reports an unsatisfied bound at the type argument.
+fn assert_impl_call(
+    type_to_impl_from: &syn::Type,
+    enum_name: &Ident,
+    ty_generics: &syn::TypeGenerics,
+    str: &syn::LitStr,
+) -> proc_macro2::TokenStream {
+    let enum_name = format_ident!("{}", enum_name, span = str.span());
+    quote_spanned!(str.span() => assert_impl::<#type_to_impl_from, #enum_name #ty_generics>())
+}
+
+/// `tokens` with every `Self` replaced by `self_ty`.
+fn replace_self(tokens: proc_macro2::TokenStream, self_ty: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "Self" => self_ty.clone(),
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced = proc_macro2::Group::new(group.delimiter(), replace_self(group.stream(), self_ty));
+                replaced.set_span(group.span());
+                proc_macro2::TokenTree::Group(replaced).into()
+            },
+            token => token.into(),
+        })
+        .collect()
+}
+
+/// The crate generated code takes `ToString`, `Box`, `Cow`, `format!` and `vec!` from: `std` with the `std` feature, and
+/// `alloc` with only the `alloc` feature, for `no_std` crates.
+fn alloc_crate() -> proc_macro2::TokenStream {
+    if cfg!(feature = "std") {
+        quote!(::std)
+    } else {
+        quote!(::alloc)
+    }
+}
+
+/// Generates `impl<E: Into<Inner>> From<E>` for a `blanket` variant, where `Inner` is the type of its field.
+fn expand_blanket(
+    enum_name: &Ident,
+    generics: &syn::Generics,
+    param: &Ident,
+    m: &MapEnumData,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let variant_ident = &m.variant_ident;
+    let inner = get_variant_unnamed_type(m.fields)
+        .ok_or_else(|| syn::Error::new_spanned(variant_ident, "`blanket` requires a tuple variant"))?;
+    let fillers = trailing_field_fillers(m.fields)?;
+    let cfg_feature = m.options.cfg_attrs();
+    let allow_deprecated = m.deprecated.then(|| quote!(#[allow(deprecated)]));
+    let on_convert = m.options.on_convert.as_ref().map(|hook| quote!(#hook(&#param);));
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let mut blanket_generics = generics.clone();
+    blanket_generics
+        .params
+        .push(syn::parse_quote!(__EnumFromVariantSource: ::core::convert::Into<#inner>));
+    let (impl_generics, _, _) = blanket_generics.split_for_impl();
+    Ok(quote! {
+        #cfg_feature
+        impl #impl_generics ::core::convert::From<__EnumFromVariantSource> for #enum_name #ty_generics #where_clause {
+            #allow_deprecated
+            fn from(#param: __EnumFromVariantSource) -> Self {
+                #on_convert
+                #enum_name::#variant_ident(::core::convert::Into::into(#param) #(, #fillers)*)
+            }
+        }
+    })
+}
+
+/// The sole variant of a `transparent` enum and the type of its field.
+fn transparent_field<'a>(
+    enum_options: &EnumOptions,
+    enum_name: &Ident,
+    variants: &'a Punctuated<syn::Variant, Comma>,
+) -> Result<Option<(&'a syn::Variant, &'a syn::Type)>, syn::Error> {
+    if !enum_options.transparent {
+        if enum_options.deref {
+            return Err(syn::Error::new_spanned(enum_name, "`deref` requires `transparent`"));
+        }
+        return Ok(None);
+    }
+    match variants.iter().collect::<Vec<_>>().as_slice() {
+        [variant] if variant.fields.len() == 1 && matches!(variant.fields, syn::Fields::Unnamed(_)) => {
+            Ok(get_variant_unnamed_type(&variant.fields).map(|field| (*variant, field)))
+        },
+        _ => Err(syn::Error::new_spanned(
+            enum_name,
+            "`transparent` requires an enum with a single variant holding a single unnamed field",
+        )),
+    }
+}
+
+/// Generates the `From` impl of a `group(..)`, matching the source's variants onto unit variants of the enum.
+fn expand_group(
+    enum_name: &Ident,
+    generics: &syn::Generics,
+    param: &Ident,
+    group: &Group,
+    variants: &Punctuated<syn::Variant, Comma>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let source = &group.source;
+    let mut arms = vec![];
+    for (source_variant, variant_ident) in group.arms.iter() {
+        let variant = variants
+            .iter()
+            .find(|variant| &variant.ident == variant_ident)
+            .ok_or_else(|| syn::Error::new_spanned(variant_ident, format!("no variant named `{}`", variant_ident)))?;
+        if !variant.fields.is_empty() {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                "`group` maps onto unit variants, which have no field to fill",
+            ));
+        }
+        let construct = match &variant.fields {
+            syn::Fields::Named(_) => quote!(#enum_name::#variant_ident {}),
+            syn::Fields::Unnamed(_) => quote!(#enum_name::#variant_ident()),
+            syn::Fields::Unit => quote!(#enum_name::#variant_ident),
+        };
+        // `{ .. }` matches source variants of any shape.
+        arms.push(quote!(#source::#source_variant { .. } => #construct,));
+    }
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#source> for #enum_name #ty_generics #where_clause {
+            fn from(#param: #source) -> Self {
+                match #param {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Generates the `From` impls of a `flatten(..)`, building each payload into its variant of the nested enum.
+fn expand_flatten(
+    enum_name: &Ident,
+    generics: &syn::Generics,
+    param: &Ident,
+    flatten: &Flatten,
+    variants: &Punctuated<syn::Variant, Comma>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let variant_ident = &flatten.variant_ident;
+    let variant = variants
+        .iter()
+        .find(|variant| &variant.ident == variant_ident)
+        .ok_or_else(|| syn::Error::new_spanned(variant_ident, format!("no variant named `{}`", variant_ident)))?;
+    let inner = match &variant.fields {
+        syn::Fields::Unnamed(fields_unnamed) if fields_unnamed.unnamed.len() == 1 => &fields_unnamed.unnamed[0].ty,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                "`flatten` requires a variant holding a single unnamed field",
+            ))
+        },
+    };
+    let impls = flatten.arms.iter().map(|(payload, inner_variant)| {
+        quote! {
+            impl #impl_generics ::core::convert::From<#payload> for #enum_name #ty_generics #where_clause {
+                fn from(#param: #payload) -> Self {
+                    #enum_name::#variant_ident(<#inner>::#inner_variant(#param))
+                }
+            }
+        }
+    });
+    Ok(quote!(#(#impls)*))
+}
+
+/// Declares the tuple struct of a `newtype Wrapper(Enum)` entry of `enum_conversions!`, whose field takes the struct's
+/// visibility.
+fn expand_newtype(newtype: &Newtype) -> proc_macro2::TokenStream {
+    let Newtype {
+        attrs,
+        vis,
+        ident,
+        inner,
+    } = newtype;
+    quote! {
+        #(#attrs)*
+        #vis struct #ident(#vis #inner);
+    }
+}
+
+/// Generates the `From` impls of one `Source => (EnumA::Variant, ..)` entry of `enum_conversions!`.
+fn expand_fan_out(fan_out: &FanOut) -> proc_macro2::TokenStream {
+    let source = &fan_out.source;
+    fan_out
+        .targets
+        .iter()
+        .map(|FanOutTarget { wrapper, variant }| {
+            // `EnumA::Variant` names the enum by everything before the variant.
+            if variant.segments.len() < 2 {
+                return syn::Error::new_spanned(variant, "expected `Enum::Variant`").to_compile_error();
+            }
+            let enum_path = syn::Path {
+                leading_colon: variant.leading_colon,
+                segments: variant
+                    .segments
+                    .iter()
+                    .take(variant.segments.len() - 1)
+                    .cloned()
+                    .collect(),
+            };
+            match wrapper {
+                Some(wrapper) => quote! {
+                    impl ::core::convert::From<#source> for #wrapper {
+                        fn from(err: #source) -> Self {
+                            #wrapper(#variant(::core::convert::Into::into(err)))
+                        }
+                    }
+                },
+                None => quote! {
+                    impl ::core::convert::From<#source> for #enum_path {
+                        fn from(err: #source) -> Self {
+                            #variant(::core::convert::Into::into(err))
+                        }
+                    }
+                },
+            }
+        })
+        .collect()
+}
+
+/// Generates `From` impls both ways between the enum and another one whose variants hold the same fields.
+fn expand_bidirectional(
+    enum_name: &Ident,
+    generics: &syn::Generics,
+    bidirectional: &Bidirectional,
+    variants: &Punctuated<syn::Variant, Comma>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let other = &bidirectional.other;
+    let (mut into_other, mut from_other) = (vec![], vec![]);
+    for variant in variants.iter() {
+        let (_, other_variant) = bidirectional
+            .pairs
+            .iter()
+            .find(|(variant_ident, _)| variant_ident == &variant.ident)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    other,
+                    format!(
+                        "`bidirectional` must map every variant, but `{}` is missing",
+                        variant.ident
+                    ),
+                )
+            })?;
+        let variant_ident = &variant.ident;
+        let bindings = match &variant.fields {
+            syn::Fields::Named(fields_named) => {
+                let names = fields_named.named.iter().map(|field| &field.ident);
+                quote!({ #(#names),* })
+            },
+            syn::Fields::Unnamed(fields_unnamed) => {
+                let names = (0..fields_unnamed.unnamed.len()).map(|i| format_ident!("field{}", i));
+                quote!((#(#names),*))
+            },
+            syn::Fields::Unit => quote!(),
+        };
+        into_other.push(quote!(#enum_name::#variant_ident #bindings => #other::#other_variant #bindings,));
+        from_other.push(quote!(#other::#other_variant #bindings => #enum_name::#variant_ident #bindings,));
+    }
+    for (variant_ident, _) in bidirectional.pairs.iter() {
+        if !variants.iter().any(|variant| &variant.ident == variant_ident) {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                format!("no variant named `{}`", variant_ident),
+            ));
+        }
+    }
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#enum_name #ty_generics> for #other #where_clause {
+            fn from(value: #enum_name #ty_generics) -> Self {
+                match value {
+                    #(#into_other)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#other> for #enum_name #ty_generics #where_clause {
+            fn from(value: #other) -> Self {
+                match value {
+                    #(#from_other)*
+                }
+            }
+        }
+    })
+}
+
+/// Generates the `Display` impl of `display_delegate`: each variant writes its first field, and unit variants their
+/// name.
+fn expand_display_delegate(
+    enum_name: &Ident,
+    generics: &syn::Generics,
+    variants: &Punctuated<syn::Variant, Comma>,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        match variant.fields.iter().next() {
+            Some(syn::Field { ident: Some(field), .. }) => quote! {
+                #enum_name::#variant_ident { #field: ref inner, .. } => ::core::fmt::Display::fmt(inner, f)
+            },
+            Some(_) => quote!(#enum_name::#variant_ident(ref inner, ..) => ::core::fmt::Display::fmt(inner, f)),
+            None => {
+                let name = variant_ident.unraw().to_string();
+                let pattern = match &variant.fields {
+                    syn::Fields::Named(_) => quote!(#enum_name::#variant_ident {}),
+                    syn::Fields::Unnamed(_) => quote!(#enum_name::#variant_ident()),
+                    syn::Fields::Unit => quote!(#enum_name::#variant_ident),
+                };
+                quote!(#pattern => f.write_str(#name))
+            },
+        }
+    });
+    let allow_deprecated = variants
+        .iter()
+        .any(|variant| is_deprecated(&variant.attrs))
+        .then(|| quote!(#[allow(deprecated)]));
+    quote! {
+        impl #impl_generics ::core::fmt::Display for #enum_name #ty_generics #where_clause {
+            #allow_deprecated
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match *self {
+                    #(#arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `From<std::io::Error>` impl that routes errors to variants by their `ErrorKind`.
+fn expand_io_kind(
+    enum_name: &Ident,
+    generics: &syn::Generics,
+    param: &Ident,
+    io_kind: &IoKind,
+    variants: &Punctuated<syn::Variant, Comma>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let alloc = alloc_crate();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    match io_kind.arms.iter().position(|arm| arm.kind.is_none()) {
+        Some(default) if default == io_kind.arms.len() - 1 => {},
+        Some(default) => {
+            return Err(syn::Error::new_spanned(
+                &io_kind.arms[default].variant_ident,
+                "the `_` arm of `io_kind` must come last",
+            ))
+        },
+        None => {
+            return Err(syn::Error::new_spanned(
+                &io_kind.key,
+                "`io_kind` requires a `_ => Variant` default arm",
+            ))
+        },
+    }
+    let mut arms = vec![];
+    for arm in io_kind.arms.iter() {
+        let variant_ident = &arm.variant_ident;
+        let variant = variants
+            .iter()
+            .find(|variant| &variant.ident == variant_ident)
+            .ok_or_else(|| syn::Error::new_spanned(variant_ident, format!("no variant named `{}`", variant_ident)))?;
+        let construct = match &variant.fields {
+            syn::Fields::Unit => quote!(#enum_name::#variant_ident),
+            syn::Fields::Unnamed(fields_unnamed) if fields_unnamed.unnamed.len() == 1 => {
+                match get_inner_ident_type(&variant.fields) {
+                    InnerIdentTypes::String => {
+                        quote!(#enum_name::#variant_ident(#alloc::string::ToString::to_string(&#param)))
+                    },
+                    _ => quote!(#enum_name::#variant_ident(#param)),
+                }
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant_ident,
+                    "`io_kind` variants must be unit variants or hold the `std::io::Error`",
+                ))
+            },
+        };
+        let pattern = match &arm.kind {
+            Some(kind) => quote!(::std::io::ErrorKind::#kind),
+            None => quote!(_),
+        };
+        arms.push(quote!(#pattern => #construct,));
+    }
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<::std::io::Error> for #enum_name #ty_generics #where_clause {
+            fn from(#param: ::std::io::Error) -> Self {
+                match #param.kind() {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+#[derive(Debug, Clone)]
+struct MapEnumDataPunctuated<'a> {
+    variant_ident: Ident,
+    /// The contents of each `#[enum_from_variant(..)]` on the variant, whose options apply to its own sources only.
+    nested_metas: Vec<Punctuated<syn::NestedMeta, Comma>>,
+    inner_ident: Option<Ident>,
+    fields: &'a syn::Fields,
+    deprecated: bool,
+    from_field: Option<&'a syn::Field>,
+}
+
+#[derive(Debug, Clone)]
+struct MapEnumData<'a> {
+    variant_ident: Ident,
+    meta: syn::NestedMeta,
+    inner_ident: Option<Ident>,
+    /// The fields of the variant, borrowed from the input so each of its sources doesn't copy them.
+    fields: &'a syn::Fields,
+    /// The variant is `#[deprecated]`, so constructing it must not warn at the expansion site.
+    deprecated: bool,
+    options: ConversionOptions,
+    /// The `#[from]` or `#[convert]` field of a struct variant, which receives the source while the others are
+    /// defaulted.
+    from_field: Option<&'a syn::Field>,
+}
+
+/// Options set by `#[enum_from_variant(..)]` on the enum itself, shared by all of its conversions.
+#[derive(Debug, Clone, Default)]
+struct EnumOptions {
+    /// `method_style = "snake" | "lowercase"`: how source type names are cased in generated method names.
+    method_style: MethodStyle,
+    /// `method_prefix = "..."`: replaces the default prefix of generated method names, e.g. `from_`.
+    method_prefix: Option<String>,
+    /// `io_kind { Kind => Variant, _ => Variant }`: a `From<std::io::Error>` routed by `ErrorKind`.
+    io_kind: Option<IoKind>,
+    /// `bidirectional(Other { Variant <-> OtherVariant, .. })`: `From` impls both ways between the two enums.
+    bidirectional: Vec<Bidirectional>,
+    /// `module = "conversions"`: generate the impls inside `mod conversions { use super::*; .. }`.
+    module: Option<Ident>,
+    /// `crate = "path"`: where generated code finds this crate, e.g. when it's only reachable through a re-export.
+    krate: Option<syn::Path>,
+    /// `bound = "Self: Send"`: a predicate added to the `where` clause of every generated impl.
+    bounds: Vec<syn::WherePredicate>,
+    /// `vis = "pub(crate)"`: the visibility of generated methods and traits, instead of the enum's own.
+    vis: Option<syn::Visibility>,
+    /// `table(("Source", Variant), ..)`: conversions listed on the enum, like `#[enum_from(..)]`.
+    table: Vec<TableEntry>,
+    /// `group("SubErr") { A => VariantA, .. }`: a `From<SubErr>` mapping each of its variants to a unit variant.
+    groups: Vec<Group>,
+    /// `flatten(Inner) { "DbError" => Database, .. }`: a `From<DbError>` building `Inner(InnerType::Database(err))`.
+    flatten: Vec<Flatten>,
+    /// `box_all("AErr", "BErr") => Other`: `boxed` conversions of every listed source into `Other`.
+    box_all: Vec<BoxAll>,
+    /// `transparent`: the enum wraps a single type, which its sole variant converts from.
+    transparent: bool,
+    /// `deref`: with `transparent`, also implement `Deref` to the wrapped type.
+    deref: bool,
+    /// `display_delegate`: implement `Display` by writing the field of each variant, or the name of a unit variant.
+    display_delegate: bool,
+    /// `doc_links`: document each generated `From` and `TryFrom` impl with links to its source and variant.
+    doc_links: bool,
+    /// `assert_impls`: check each generated `From` and `TryFrom` impl where it is declared, in a function never called.
+    assert_impls: bool,
+    /// `validate_only`: report every problem with the conversions, but generate nothing.
+    validate_only: bool,
+    /// `param = "source"`: the name of the source in generated functions and `via` expressions, instead of `err`.
+    param: Option<Ident>,
+    /// `debug`: print the generated code while compiling.
+    debug: bool,
+    /// `emit_count`: generate a `GENERATED_FROM_COUNT` const, for tests. Requires the `testing` feature.
+    emit_count: bool,
+    /// `strict`: never guess how a source becomes its field; conversions must say `move`, `stringify`, ... instead.
+    strict: bool,
+}
+
+/// `Other { Variant <-> OtherVariant, .. }`: the enum-level `bidirectional(..)` option.
+#[derive(Debug, Clone)]
+struct Bidirectional {
+    other: syn::Path,
+    pairs: Vec<(Ident, Ident)>,
+}
+
+impl Parse for Bidirectional {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let other = input.parse()?;
+        let content;
+        syn::braced!(content in input);
+        let mut pairs = vec![];
+        while !content.is_empty() {
+            let variant_ident = content.parse()?;
+            content.parse::<Token![<]>()?;
+            content.parse::<Token![->]>()?;
+            pairs.push((variant_ident, content.parse()?));
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+        Ok(Bidirectional { other, pairs })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IoKind {
+    key: Ident,
+    arms: Vec<IoKindArm>,
+}
+
+/// `NotFound => Missing`, or `_ => Io` when `kind` is `None`.
+#[derive(Debug, Clone)]
+struct IoKindArm {
+    kind: Option<Ident>,
+    variant_ident: Ident,
+}
+
+impl Parse for IoKindArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let kind = if input.peek(Token![_]) {
+            input.parse::<Token![_]>()?;
+            None
+        } else {
+            Some(input.parse()?)
+        };
+        input.parse::<Token![=>]>()?;
+        let variant_ident = input.parse()?;
+        Ok(IoKindArm { kind, variant_ident })
+    }
+}
+
+/// One `("Source", Variant)` entry of the enum-level `table(..)` option.
+#[derive(Debug, Clone)]
+struct TableEntry {
+    source: syn::LitStr,
+    variant_ident: Ident,
+}
+
+impl Parse for TableEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let source = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let variant_ident = content.parse()?;
+        Ok(TableEntry { source, variant_ident })
+    }
+}
+
+/// `group("SubErr") { A => VariantA, B => VariantB }`: one `From<SubErr>` routing each of its variants to a unit
+/// variant.
+#[derive(Debug, Clone)]
+struct Group {
+    source: syn::Path,
+    arms: Vec<(Ident, Ident)>,
+}
+
+impl Group {
+    fn parse(source: proc_macro2::TokenStream, arms: proc_macro2::TokenStream) -> syn::Result<Self> {
+        let source: syn::LitStr = syn::parse2(source)?;
+        let arms = Punctuated::<GroupArm, Comma>::parse_terminated.parse2(arms)?;
+        Ok(Group {
+            source: source.parse()?,
+            arms: arms
+                .into_iter()
+                .map(|arm| (arm.source_variant, arm.variant_ident))
+                .collect(),
+        })
+    }
+}
+
+/// One `A => VariantA` arm of `group(..)`.
+struct GroupArm {
+    source_variant: Ident,
+    variant_ident: Ident,
+}
+
+impl Parse for GroupArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let source_variant = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let variant_ident = input.parse()?;
+        Ok(GroupArm {
+            source_variant,
+            variant_ident,
+        })
+    }
+}
+
+/// `flatten(Inner) { "DbError" => Database, .. }`: `From` impls building each payload into a variant of the enum
+/// `Inner` holds.
+#[derive(Debug, Clone)]
+struct Flatten {
+    variant_ident: Ident,
+    arms: Vec<(syn::Type, Ident)>,
+}
+
+impl Flatten {
+    fn parse(variant_ident: proc_macro2::TokenStream, arms: proc_macro2::TokenStream) -> syn::Result<Self> {
+        let arms = Punctuated::<FlattenArm, Comma>::parse_terminated.parse2(arms)?;
+        Ok(Flatten {
+            variant_ident: syn::parse2(variant_ident)?,
+            arms: arms.into_iter().map(|arm| (arm.payload, arm.inner_variant)).collect(),
+        })
+    }
+}
+
+/// One `"DbError" => Database` arm of `flatten(..)`.
+struct FlattenArm {
+    payload: syn::Type,
+    inner_variant: Ident,
+}
+
+impl Parse for FlattenArm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let payload = input.parse::<syn::LitStr>()?.parse()?;
+        input.parse::<Token![=>]>()?;
+        let inner_variant = input.parse()?;
+        Ok(FlattenArm { payload, inner_variant })
+    }
+}
+
+/// `box_all("AErr", "BErr") => Other`: boxed conversions of several sources into one variant.
+#[derive(Debug, Clone)]
+struct BoxAll {
+    sources: Vec<syn::LitStr>,
+    variant_ident: Ident,
+}
+
+/// An item of the enum-level attribute: a regular meta item, a `key { .. }` or `key(..)` block, a `key(..) => Variant`
+/// block routed to a variant, or a `key(..) { .. }` block with arguments.
+enum EnumAttributeItem {
+    Meta(syn::NestedMeta),
+    Block(Ident, proc_macro2::TokenStream),
+    Routed(Ident, proc_macro2::TokenStream, Ident),
+    Keyed(Ident, proc_macro2::TokenStream, proc_macro2::TokenStream),
+}
+
+impl Parse for EnumAttributeItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(syn::token::Brace) {
+            let key = input.parse()?;
+            let content;
+            syn::braced!(content in input);
+            return Ok(EnumAttributeItem::Block(key, content.parse()?));
+        }
+        if input.peek(syn::Ident) && input.peek2(syn::token::Paren) {
+            let key = input.parse()?;
+            let content;
+            syn::parenthesized!(content in input);
+            if input.peek(Token![=>]) {
+                input.parse::<Token![=>]>()?;
+                return Ok(EnumAttributeItem::Routed(key, content.parse()?, input.parse()?));
+            }
+            if input.peek(syn::token::Brace) {
+                let block;
+                syn::braced!(block in input);
+                return Ok(EnumAttributeItem::Keyed(key, content.parse()?, block.parse()?));
+            }
+            return Ok(EnumAttributeItem::Block(key, content.parse()?));
+        }
+        input.parse().map(EnumAttributeItem::Meta)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum MethodStyle {
+    /// `from_db_error`
+    #[default]
+    Snake,
+    /// `from_dberror`
+    Lowercase,
+}
+
+impl EnumOptions {
+    /// Name of a generated helper method for `source`, e.g. `from_db_error` for `DbError`.
+    fn method_name(&self, default_prefix: &str, source: &Ident) -> Ident {
+        let prefix = self.method_prefix.as_deref().unwrap_or(default_prefix);
+        let name = match self.method_style {
+            MethodStyle::Snake => to_snake_case(&source.unraw().to_string()),
+            MethodStyle::Lowercase => source.unraw().to_string().to_lowercase(),
+        };
+        format_ident!("{}{}", prefix, name, span = source.span())
+    }
+}
+
+/// Options that apply to every conversion declared by a single `#[enum_from_variant(..)]` attribute.
+#[derive(Debug, Clone, Default)]
+struct ConversionOptions {
+    /// `feature = "name"`: only emit the impl when the downstream crate's `name` feature is enabled.
+    feature: Option<syn::LitStr>,
+    /// `test_only`: only emit the impl under `#[cfg(test)]`, e.g. for a mock source.
+    test_only: bool,
+    /// `boxed`: store the source as `Box::new(err)`, whatever the field type looks like.
+    boxed: bool,
+    /// `boxed_dyn`: like `boxed`, for a `Box<dyn Error + Send + Sync>` field, requiring those bounds of the source.
+    boxed_dyn: bool,
+    /// `anyhow`: store `anyhow::Error::new(err)`, for an `anyhow::Error` field, with the bounds of `boxed_dyn`.
+    anyhow: bool,
+    /// `also_str`: on a `String` field, also generate `From<&str>`.
+    also_str: bool,
+    /// `also_boxed`: also generate `From<Box<T>>`, moving the source out of the box.
+    also_boxed: bool,
+    /// `chain`: fill a `{ message, source }` struct variant with the source's message and the boxed source itself.
+    chain: bool,
+    /// `collect`: store `err.into_iter().collect()`, or a one-element vec for a `Vec` of the source.
+    collect: bool,
+    /// `on_convert = "path::to::hook"`: call `hook(&err)` before the variant is constructed.
+    on_convert: Option<syn::Path>,
+    /// `try`: generate `TryFrom` instead of `From`, converting the source with `TryInto` into the field type.
+    fallible: bool,
+    /// `keep_source`: with `try`, fail with `(source, ConversionError)`, handing a `Clone` source back to the caller.
+    keep_source: bool,
+    /// `into_turbofish = "Target"`: store `Into::<Target>::into(err)`, naming the target where inference can't.
+    into_turbofish: Option<syn::Type>,
+    /// `via = "expr"`: store the result of an expression over the `err` binding, or of a closure called with the source.
+    /// An expression that builds the enum itself, like `Self::Variant { .. }`, is used as the whole conversion.
+    via: Option<syn::Expr>,
+    /// `when = "!err.is_empty()"`: with `try`, a predicate over the `err` binding that the source must pass, failing the
+    /// conversion with `ConversionError` otherwise.
+    when: Option<syn::Expr>,
+    /// `where = "E: Error + Send, T: From<E>"`: predicates added to the `where` clause of this conversion's impl only.
+    predicates: Vec<syn::WherePredicate>,
+    /// `via_ref`: convert from `&T` instead of `T`, with `via` working on the reference.
+    via_ref: bool,
+    /// `prefix = "context: "`: store `format!("context: {}", err)`.
+    prefix: Option<syn::LitStr>,
+    /// `wrap = "Newtype"`: store `Newtype(err)`, for a field that is a tuple struct around the source.
+    wrap: Option<syn::Path>,
+    /// `unwrap`: store `err.0`, for a source that is a tuple struct around the field's type.
+    unwrap: bool,
+    /// `through = "Mid"`: store `Mid::from(err)`, for a field whose own type converts from the source.
+    through: Option<syn::Type>,
+    /// `error = "Type"`: the `TryFrom::Error` of a `try` conversion, instead of `ConversionError`.
+    error: Option<syn::Type>,
+    /// `ok`: for a `Result<T, E>` source, generate `TryFrom` building the variant from `T` and failing on `E`.
+    result_ok: bool,
+    /// `err`: for a `Result<_, E>` source, generate a `from_<e>_result` method mapping its `Err` into the variant.
+    result_err: bool,
+    /// `assert_display`: on a stringifying conversion, check up front that the source implements `Display`.
+    assert_display: bool,
+    /// `result`: also generate `err_<variant>(err) -> Result<T, Self>`, returning the conversion as an `Err`.
+    result: bool,
+    /// `backtrace`: in a struct variant, capture a `Backtrace` into its `backtrace` field.
+    backtrace: bool,
+    /// `timestamp`: in a struct variant, store `SystemTime::now()` into its `when` field.
+    timestamp: bool,
+    /// `count`: count the conversions in a static, read through a generated `from_<source>_count()`.
+    count: bool,
+    /// `track_caller`: mark the generated conversion `#[track_caller]`, so panics in `via` or hooks point at the caller.
+    track_caller: bool,
+    /// `cold`: mark the generated conversion `#[cold]`, hinting that it sits on an unlikely error path.
+    cold: bool,
+    /// `manual`: the `From` or `TryFrom` impl of this conversion is written by hand, so none is generated.
+    manual: bool,
+    /// `ext`: generate a `from_<source>` method on the `<Enum>FromExt` trait instead of a `From` impl.
+    ext: bool,
+    /// `blanket`: generate `From<E>` for every `E: Into<Field>`, in place of a source type.
+    blanket: bool,
+    /// `accessors`: generate `is_<variant>` and `as_<variant>` methods on the enum.
+    accessors: bool,
+    /// `into_method`: generate a consuming `into_<variant>(self) -> Result<Field, Self>` method on the enum.
+    into_method: bool,
+    /// `from`: take the source type from the field itself and move the source in, like `thiserror`'s `#[from]`.
+    from: bool,
+    /// `from_variant = "Database"`: convert from the type the `Database` variant holds, e.g. to re-route it.
+    from_variant: Option<Ident>,
+    /// `move`, `stringify`, `into` or `to_owned`: how the source becomes the field, instead of guessing from the field type.
+    /// `debug_string` formats the source with `{:?}`, `cast` converts it with `as`, and `miette_source` is `move` for a
+    /// field that `miette` reads as the diagnostic source.
+    mode: Option<ValueMode>,
+}
+
+impl ConversionOptions {
+    /// Whether the conversion implements `From` or `TryFrom` of its source on the enum, which only one conversion may;
+    /// `ext` and `err` conversions generate methods instead.
+    fn claims_source(&self) -> bool {
+        !self.ext && !self.result_err && !self.blanket
+    }
+
+    /// The `#[cfg(..)]` attributes of `feature` and `test_only`, put on every item generated for the conversion.
+    fn cfg_attrs(&self) -> proc_macro2::TokenStream {
+        let feature = self.feature.as_ref().map(|feature| quote!(#[cfg(feature = #feature)]));
+        let test_only = self.test_only.then(|| quote!(#[cfg(test)]));
+        quote!(#feature #test_only)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ValueMode {
+    /// `err`
+    Move,
+    /// `err.to_string()`
+    Stringify,
+    /// `format!("{:?}", err)`, for sources that only implement `Debug`
+    DebugString,
+    /// `Into::into(err)`
+    Into,
+    /// `ToOwned::to_owned(err)`
+    ToOwned,
+    /// `err as Field`, for a deliberately lossy primitive conversion
+    Cast,
+}
+
+#[derive(Debug)]
+enum InnerIdentTypes {
+    String,
+    Cow,
+    /// `Option<Box<T>>`, built as `Some(Box::new(err))`.
+    OptionBox,
+    Named,
+    Unnamed,
+}
+
+/// Classifies the first field of a variant by its type. Only the standard `String`, `Cow`, `Option` and `Box` count:
+/// a path such as `errors::String` names a type of the user's, and so does a type macro such as `payload!()`, which
+/// can't be looked into before it expands. Any other field, like a reference, tuple or function pointer, is moved into
+/// as is.
+fn get_inner_ident_type(fields: &syn::Fields) -> InnerIdentTypes {
+    match source_field(fields).map(|field| ungroup_type(&field.ty)) {
+        Some(syn::Type::Path(type_path)) => match std_type_ident(type_path) {
+            Some(ident) if ident == "String" => InnerIdentTypes::String,
+            Some(ident) if ident == "Cow" => InnerIdentTypes::Cow,
+            Some(ident) if ident == "Option" && is_std_box(single_type_argument(type_path)) => {
+                InnerIdentTypes::OptionBox
+            },
+            _ => InnerIdentTypes::Named,
+        },
+        Some(_) => InnerIdentTypes::Named,
+        None => InnerIdentTypes::Unnamed,
+    }
+}
+
+/// The last segment of `type_path` if it's unqualified or under `std`, `core` or `alloc`.
+fn std_type_ident(type_path: &syn::TypePath) -> Option<&Ident> {
+    let segments = &type_path.path.segments;
+    let is_std = segments.len() == 1
+        || segments
+            .first()
+            .is_some_and(|segment| segment.ident == "std" || segment.ident == "core" || segment.ident == "alloc");
+    segments.last().map(|segment| &segment.ident).filter(|_| is_std)
+}
+
+/// The `T` of `Wrapper<T>`.
+fn single_type_argument(type_path: &syn::TypePath) -> Option<&syn::Type> {
+    match &type_path.path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => match arguments.args.first() {
+            Some(syn::GenericArgument::Type(ty)) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `source` is `field` itself, or a standard type that the standard library converts into a `String`, `Box`
+/// or `Vec` field, e.g. `&str` or `char` into `String`.
+fn std_converts_into(source: &syn::Type, field: &syn::Type) -> bool {
+    // The type arguments of `name`, if `ty` is the standard type of that name.
+    fn std_path<'a>(ty: &'a syn::Type, name: &str) -> Option<Vec<&'a syn::Type>> {
+        match ungroup_type(ty) {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                std_type_ident(type_path).filter(|ident| *ident == name)?;
+                match &type_path.path.segments.last()?.arguments {
+                    syn::PathArguments::AngleBracketed(arguments) => Some(
+                        arguments
+                            .args
+                            .iter()
+                            .filter_map(|argument| match argument {
+                                syn::GenericArgument::Type(ty) => Some(ty),
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                    _ => Some(vec![]),
+                }
+            },
+            _ => None,
+        }
+    }
+    fn argument<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+        std_path(ty, name)?.first().copied()
+    }
+    fn referenced(ty: &syn::Type) -> Option<&syn::Type> {
+        match ungroup_type(ty) {
+            syn::Type::Reference(reference) => Some(&reference.elem),
+            _ => None,
+        }
+    }
+    fn slice_element(ty: &syn::Type) -> Option<&syn::Type> {
+        match ungroup_type(ty) {
+            syn::Type::Slice(slice) => Some(&slice.elem),
+            _ => None,
+        }
+    }
+    fn array_element(ty: &syn::Type) -> Option<&syn::Type> {
+        match ungroup_type(ty) {
+            syn::Type::Array(array) => Some(&array.elem),
+            _ => None,
+        }
+    }
+    fn same(a: &syn::Type, b: &syn::Type) -> bool {
+        ungroup_type(a).to_token_stream().to_string() == ungroup_type(b).to_token_stream().to_string()
+    }
+    let is_str = |ty: &syn::Type| std_path(ty, "str").is_some();
+    // `&str`, `String` and `Cow<str>` convert into `String`, `Box<str>` and `Box<dyn Error>` alike.
+    let is_text = |ty: &syn::Type| {
+        referenced(ty).is_some_and(is_str)
+            || std_path(ty, "String").is_some()
+            || argument(ty, "Cow").is_some_and(is_str)
+    };
+    if same(source, field) {
+        return true;
+    }
+    if std_path(field, "String").is_some() {
+        return is_text(source)
+            || referenced(source).is_some_and(|ty| std_path(ty, "String").is_some())
+            || std_path(source, "char").is_some()
+            || argument(source, "Box").is_some_and(is_str);
+    }
+    if let Some(inner) = argument(field, "Box") {
+        // `Vec<T>`, `&[T]`, `Cow<[T]>` and `[T; N]` convert into `Box<[T]>`.
+        let slice_of = |elem: &syn::Type| {
+            let holds = |ty: &syn::Type| same(ty, elem);
+            argument(source, "Vec").is_some_and(holds)
+                || referenced(source)
+                    .or(argument(source, "Cow"))
+                    .and_then(slice_element)
+                    .is_some_and(holds)
+                || array_element(source).is_some_and(holds)
+        };
+        return same(source, inner)
+            || ((is_str(inner) || matches!(ungroup_type(inner), syn::Type::TraitObject(_))) && is_text(source))
+            || slice_element(inner).is_some_and(slice_of);
+    }
+    if let Some(elem) = argument(field, "Vec") {
+        let holds = |ty: &syn::Type| same(ty, elem);
+        return referenced(source)
+            .and_then(|ty| slice_element(ty).or(array_element(ty)))
+            .is_some_and(holds)
+            || array_element(source).is_some_and(holds)
+            || argument(source, "Box")
+                .or(argument(source, "Cow"))
+                .and_then(slice_element)
+                .is_some_and(holds)
+            || argument(source, "VecDeque").is_some_and(holds)
+            || argument(source, "BinaryHeap").is_some_and(holds)
+            || (std_path(elem, "u8").is_some()
+                && (std_path(source, "String").is_some()
+                    || std_path(source, "CString").is_some()
+                    || referenced(source).is_some_and(is_str)));
+    }
+    false
+}
+
+fn is_std_box(ty: Option<&syn::Type>) -> bool {
+    match ty.map(ungroup_type) {
+        Some(syn::Type::Path(type_path)) => std_type_ident(type_path).is_some_and(|ident| ident == "Box"),
+        _ => false,
+    }
+}
+
+/// Reads the `#[enum_from_variant(..)]` attribute of a variant, and the `#[from]` field of a struct variant.
+///
+/// Returns `Ok(None)` when the variant carries neither, so it can be skipped, and an error when the attribute is
+/// present but malformed. Other attributes (docs, serde, ...) are ignored.
+pub(crate) fn get_attributes(variants: &syn::Variant) -> Result<Option<MapEnumDataPunctuated<'_>>, syn::Error> {
+    let variant_ident = &variants.ident;
+    let fields = &variants.fields;
+    let deprecated = is_deprecated(&variants.attrs);
+    let from_field = get_from_field(variant_ident, fields)?;
+    let mut nested_metas = vec![];
+    for attribute in variants.attrs.iter() {
+        if !attribute.path.is_ident("enum_from_variant") {
+            continue;
+        }
+        nested_metas.push(match attribute.parse_meta()? {
+            syn::Meta::List(syn::MetaList { nested, .. }) => nested,
+            _ => {
+                return syn::Result::Err(syn::Error::new_spanned(
+                    &attribute.tokens,
+                    "expected #[enum_from_variant(..)]".to_string(),
+                ))
+            },
+        });
+    }
+    if nested_metas.is_empty() {
+        if from_field.is_none() {
+            return syn::Result::Ok(None);
+        }
+        nested_metas.push(Punctuated::new());
+    }
+    syn::Result::Ok(Some(MapEnumDataPunctuated {
+        variant_ident: variant_ident.to_owned(),
+        nested_metas,
+        inner_ident: get_variant_unnamed_ident(fields),
+        fields,
+        deprecated,
+        from_field,
+    }))
+}
+
+/// The field marked `#[from]` or `#[convert]`; marking more than one is ambiguous. In a tuple variant, only the first
+/// field can be marked, as the others are filled in.
+fn get_from_field<'a>(variant_ident: &Ident, fields: &'a syn::Fields) -> Result<Option<&'a syn::Field>, syn::Error> {
+    let is_marked = |field: &&syn::Field| {
+        field
+            .attrs
+            .iter()
+            .any(|attribute| attribute.path.is_ident("from") || attribute.path.is_ident("convert"))
+    };
+    let mut from_fields = fields.iter().filter(is_marked);
+    let from_field = from_fields.next();
+    if from_fields.next().is_some() {
+        return Err(syn::Error::new_spanned(
+            variant_ident,
+            "only one field can be marked `#[from]`: it is the source the variant is converted from",
+        ));
+    }
+    if let (Some(field), syn::Fields::Unnamed(_)) = (from_field, fields) {
+        if fields.iter().position(|other| std::ptr::eq(other, field)) != Some(0) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "only the first field of a tuple variant can be the source",
+            ));
+        }
+    }
+    Ok(from_field)
+}
+
+fn is_deprecated(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attribute| attribute.path.is_ident("deprecated"))
+}
+
+/// The field a variant attribute converts into: the first field of a tuple variant, or the only field, or else the
+/// `source` field, of a struct variant.
+fn source_field(fields: &syn::Fields) -> Option<&syn::Field> {
+    match fields {
+        syn::Fields::Unnamed(fields_unnamed) => fields_unnamed.unnamed.first(),
+        syn::Fields::Named(fields_named) if fields_named.named.len() == 1 => fields_named.named.first(),
+        syn::Fields::Named(fields_named) => fields_named
+            .named
+            .iter()
+            .find(|field| field.ident.as_ref().is_some_and(|ident| ident == "source")),
+        syn::Fields::Unit => None,
+    }
+}
+
+/// Initializers for the fields of a struct variant besides the one the source goes into: with `backtrace`, a captured
+/// `Backtrace` for the field named `backtrace` or of that type, with `timestamp`, `SystemTime::now()` for the field
+/// named `when` or `timestamp`, or of type `SystemTime`, and `Default::default()` for fields marked
+/// `#[enum_from_variant(default)]`. `()` and `PhantomData` fields are filled in as after a tuple variant's field; any
+/// other field is an error rather than a silent default.
+fn other_fields(
+    fields: &syn::Fields,
+    source: &syn::Field,
+    options: &ConversionOptions,
+) -> Result<Vec<proc_macro2::TokenStream>, syn::Error> {
+    let is_backtrace = |field: &syn::Field| {
+        field.ident.as_ref().is_some_and(|ident| ident == "backtrace")
+            || get_type_ident(&field.ty).is_some_and(|ident| ident == "Backtrace")
+    };
+    let is_timestamp = |field: &syn::Field| {
+        field
+            .ident
+            .as_ref()
+            .is_some_and(|ident| ident == "when" || ident == "timestamp")
+            || get_type_ident(&field.ty).is_some_and(|ident| ident == "SystemTime")
+    };
+    let others: Vec<_> = fields.iter().filter(|field| !std::ptr::eq(*field, source)).collect();
+    if options.backtrace && !others.iter().any(|field| is_backtrace(field)) {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "`backtrace` requires a `backtrace: std::backtrace::Backtrace` field",
+        ));
+    }
+    if options.timestamp && !others.iter().any(|field| is_timestamp(field)) {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "`timestamp` requires a `when: std::time::SystemTime` field",
+        ));
+    }
+    let mut initializers = vec![];
+    for field in others {
+        let Some(ident) = field.ident.as_ref() else {
+            continue;
+        };
+        initializers.push(if options.backtrace && is_backtrace(field) {
+            quote!(#ident: ::std::backtrace::Backtrace::capture())
+        } else if options.timestamp && is_timestamp(field) {
+            quote!(#ident: ::std::time::SystemTime::now())
+        } else if is_marked_default(field)? {
+            quote!(#ident: ::core::default::Default::default())
+        } else if let Some(filler) = zero_sized_filler(&field.ty) {
+            quote!(#ident: #filler)
+        } else {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "`{}` isn't the source of the conversion: mark it `#[enum_from_variant(default)]` to fill it \
+                     with `Default::default()`",
+                    ident
+                ),
+            ));
+        });
+    }
+    Ok(initializers)
+}
+
+/// Whether a struct variant's field is marked `#[enum_from_variant(default)]`.
+fn is_marked_default(field: &syn::Field) -> Result<bool, syn::Error> {
+    let mut marked = false;
+    for attribute in field
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("enum_from_variant"))
+    {
+        match attribute.parse_args::<Ident>() {
+            Ok(ident) if ident == "default" => marked = true,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    attribute,
+                    "expected #[enum_from_variant(default)] on a field",
+                ))
+            },
+        }
+    }
+    Ok(marked)
+}
+
+fn get_variant_unnamed_type(fields: &syn::Fields) -> Option<&syn::Type> {
+    if let syn::Fields::Unnamed(fields_unnamed) = fields {
+        return fields_unnamed.unnamed.first().map(|field| &field.ty);
+    }
+    None
+}
+
+fn get_variant_unnamed_ident(fields: &syn::Fields) -> Option<Ident> {
+    if let syn::Fields::Unnamed(fields_unnamed) = fields {
+        let syn::FieldsUnnamed { unnamed, .. } = fields_unnamed;
+        if let Some(field) = unnamed.iter().next() {
+            let type_path = if let syn::Type::Path(type_path, ..) = ungroup_type(&field.ty) {
+                type_path
+            } else {
+                return None;
+            };
+            return type_path.path.segments.last().map(|segment| segment.ident.to_owned());
+        };
+    }
+    None
+}
+
+fn get_conversion_options(nested_meta: &Punctuated<syn::NestedMeta, Comma>) -> Result<ConversionOptions, syn::Error> {
+    let mut options = ConversionOptions::default();
+    for meta in nested_meta.iter() {
+        if let syn::NestedMeta::Meta(meta) = meta {
+            let key = meta
+                .path()
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            match (key.as_str(), meta) {
+                (key, syn::Meta::NameValue(_) | syn::Meta::List(_)) if !VALUE_OPTIONS.contains(&key) => {
+                    return Err(misused_option(meta, key))
+                },
+                (key, syn::Meta::NameValue(name_value)) => set_value_option(&mut options, key, &name_value.lit)?,
+                // `key(a, b)` reads as `key = a, key = b`, e.g. `where("T: Display", "U: Debug")`.
+                (key, syn::Meta::List(list)) => {
+                    for nested in list.nested.iter() {
+                        match nested {
+                            syn::NestedMeta::Lit(lit) => set_value_option(&mut options, key, lit)?,
+                            syn::NestedMeta::Meta(meta) => {
+                                return Err(syn::Error::new_spanned(
+                                    meta,
+                                    format!("expected a literal, as in `{} = ..`", key),
+                                ))
+                            },
+                        }
+                    }
+                },
+                ("boxed", syn::Meta::Path(_)) => options.boxed = true,
+                ("boxed_dyn", syn::Meta::Path(_)) => options.boxed_dyn = true,
+                ("anyhow", syn::Meta::Path(_)) => options.anyhow = true,
+                ("also_str", syn::Meta::Path(_)) => options.also_str = true,
+                ("also_boxed", syn::Meta::Path(_)) => options.also_boxed = true,
+                ("via_ref", syn::Meta::Path(_)) => options.via_ref = true,
+                ("count", syn::Meta::Path(_)) => options.count = true,
+                ("backtrace", syn::Meta::Path(_)) => options.backtrace = true,
+                ("timestamp", syn::Meta::Path(_)) => options.timestamp = true,
+                ("result", syn::Meta::Path(_)) => options.result = true,
+                ("chain", syn::Meta::Path(_)) => options.chain = true,
+                ("collect", syn::Meta::Path(_)) => options.collect = true,
+                ("ext", syn::Meta::Path(_)) => options.ext = true,
+                ("unwrap", syn::Meta::Path(_)) => options.unwrap = true,
+                ("manual", syn::Meta::Path(_)) => options.manual = true,
+                ("test_only", syn::Meta::Path(_)) => options.test_only = true,
+                ("err", syn::Meta::Path(_)) => options.result_err = true,
+                ("ok", syn::Meta::Path(_)) => options.result_ok = true,
+                ("track_caller", syn::Meta::Path(_)) => options.track_caller = true,
+                ("cold", syn::Meta::Path(_)) => options.cold = true,
+                ("assert_display", syn::Meta::Path(_)) => options.assert_display = true,
+                ("try", syn::Meta::Path(_)) => options.fallible = true,
+                ("keep_source", syn::Meta::Path(_)) => options.keep_source = true,
+                ("blanket", syn::Meta::Path(_)) => options.blanket = true,
+                ("accessors", syn::Meta::Path(_)) => options.accessors = true,
+                ("into_method", syn::Meta::Path(_)) => options.into_method = true,
+                ("from", syn::Meta::Path(_)) => options.from = true,
+                ("move", syn::Meta::Path(_)) | ("miette_source", syn::Meta::Path(_)) => {
+                    options.mode = Some(ValueMode::Move)
+                },
+                ("stringify", syn::Meta::Path(_)) => options.mode = Some(ValueMode::Stringify),
+                ("debug_string", syn::Meta::Path(_)) => options.mode = Some(ValueMode::DebugString),
+                ("into", syn::Meta::Path(_)) => options.mode = Some(ValueMode::Into),
+                ("to_owned", syn::Meta::Path(_)) => options.mode = Some(ValueMode::ToOwned),
+                ("cast", syn::Meta::Path(_)) => options.mode = Some(ValueMode::Cast),
+                (key, meta) => return Err(misused_option(meta, key)),
+            }
+        }
+    }
+    Ok(options)
+}
+
+/// The options of a conversion that take a value, `key = "value"`.
+const VALUE_OPTIONS: &[&str] = &[
+    "feature",
+    "on_convert",
+    "into_turbofish",
+    "via",
+    "error",
+    "through",
+    "prefix",
+    "wrap",
+    "from_variant",
+    "where",
+    "when",
+];
+
+/// The options of a conversion that are a bare flag.
+const FLAG_OPTIONS: &[&str] = &[
+    "boxed",
+    "boxed_dyn",
+    "anyhow",
+    "also_str",
+    "also_boxed",
+    "via_ref",
+    "count",
+    "backtrace",
+    "timestamp",
+    "result",
+    "chain",
+    "collect",
+    "ext",
+    "unwrap",
+    "test_only",
+    "manual",
+    "err",
+    "ok",
+    "track_caller",
+    "cold",
+    "assert_display",
+    "try",
+    "keep_source",
+    "blanket",
+    "accessors",
+    "into_method",
+    "from",
+    "move",
+    "miette_source",
+    "stringify",
+    "debug_string",
+    "into",
+    "to_owned",
+    "cast",
+];
+
+/// The error for an option key that is misspelled, or written in a form it doesn't take, e.g. `try = "yes"`.
+fn misused_option(tokens: impl ToTokens, key: &str) -> syn::Error {
+    let message = if VALUE_OPTIONS.contains(&key) {
+        format!("expected `{} = \"..\"`", key)
+    } else if FLAG_OPTIONS.contains(&key) {
+        format!("`{}` takes no value", key)
+    } else {
+        let known: Vec<_> = VALUE_OPTIONS
+            .iter()
+            .chain(FLAG_OPTIONS)
+            .map(|option| format!("`{}`", option))
+            .collect();
+        format!("unknown option, expected one of {}", known.join(", "))
+    };
+    syn::Error::new_spanned(tokens, message)
+}
+
+/// The options of the enum-level attribute that take a value, `key = "value"`.
+const ENUM_VALUE_OPTIONS: &[&str] = &[
+    "method_style",
+    "method_prefix",
+    "vis",
+    "bound",
+    "crate",
+    "module",
+    "param",
+];
+
+/// The options of the enum-level attribute that are a bare flag.
+const ENUM_FLAG_OPTIONS: &[&str] = &[
+    "strict",
+    "debug",
+    "emit_count",
+    "transparent",
+    "deref",
+    "display_delegate",
+    "doc_links",
+    "assert_impls",
+    "validate_only",
+];
+
+/// The options of the enum-level attribute that take a block, with the form it is written in.
+const ENUM_BLOCK_OPTIONS: &[(&str, &str)] = &[
+    ("io_kind", "io_kind { .. }"),
+    ("bidirectional", "bidirectional(Other { .. })"),
+    ("table", "table(..)"),
+    ("box_all", "box_all(..) => Variant"),
+    ("group", "group(\"Source\") { .. }"),
+    ("flatten", "flatten(Variant) { .. }"),
+];
+
+/// The error for an enum-level option key that is misspelled, or written in a form it doesn't take, like
+/// `misused_option` for the options of a conversion.
+fn misused_enum_option(tokens: impl ToTokens, key: &str) -> syn::Error {
+    let message = if ENUM_VALUE_OPTIONS.contains(&key) {
+        format!("expected `{} = \"..\"`", key)
+    } else if ENUM_FLAG_OPTIONS.contains(&key) {
+        format!("`{}` takes no value", key)
+    } else if let Some((_, form)) = ENUM_BLOCK_OPTIONS.iter().find(|(option, _)| *option == key) {
+        format!("expected `{}`", form)
+    } else {
+        let known: Vec<_> = ENUM_VALUE_OPTIONS
+            .iter()
+            .chain(ENUM_FLAG_OPTIONS)
+            .chain(ENUM_BLOCK_OPTIONS.iter().map(|(option, _)| option))
+            .map(|option| format!("`{}`", option))
+            .collect();
+        format!("unknown option, expected one of {}", known.join(", "))
+    };
+    syn::Error::new_spanned(tokens, message)
+}
+
+/// Applies a `key = value` option of a conversion.
+fn set_value_option(options: &mut ConversionOptions, key: &str, lit: &syn::Lit) -> Result<(), syn::Error> {
+    match (key, lit) {
+        ("feature", syn::Lit::Str(str)) => options.feature = Some(str.to_owned()),
+        ("on_convert", syn::Lit::Str(str)) => options.on_convert = Some(str.parse()?),
+        ("into_turbofish", syn::Lit::Str(str)) => options.into_turbofish = Some(str.parse()?),
+        ("via", syn::Lit::Str(str)) => {
+            let via: syn::Expr = str.parse()?;
+            // The closure is called with the source alone.
+            if matches!(&via, syn::Expr::Closure(closure) if closure.inputs.len() != 1) {
+                return Err(syn::Error::new(
+                    str.span(),
+                    "a `via` closure takes the source as its only argument, e.g. `|err| ..`",
+                ));
+            }
+            options.via = Some(via);
+        },
+        ("error", syn::Lit::Str(str)) => options.error = Some(str.parse()?),
+        ("when", syn::Lit::Str(str)) => options.when = Some(str.parse()?),
+        ("through", syn::Lit::Str(str)) => options.through = Some(str.parse()?),
+        ("prefix", syn::Lit::Str(str)) => options.prefix = Some(str.to_owned()),
+        ("wrap", syn::Lit::Str(str)) => options.wrap = Some(str.parse()?),
+        ("from_variant", syn::Lit::Str(str)) => options.from_variant = Some(str.parse()?),
+        ("where", syn::Lit::Str(str)) => options
+            .predicates
+            .extend(str.parse_with(Punctuated::<syn::WherePredicate, Comma>::parse_terminated)?),
+        (key, lit) => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("expected a string, as in `{} = \"..\"`", key),
+            ))
+        },
+    }
+    Ok(())
+}
+
+/// Looks through `(T)` and the invisible groups `macro_rules!` wraps `$t:ty` fragments in.
+fn ungroup_type(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Paren(syn::TypeParen { elem, .. }) | syn::Type::Group(syn::TypeGroup { elem, .. }) => {
+            ungroup_type(elem)
+        },
+        ty => ty,
+    }
+}
+
+/// The final path segment of a source type, e.g. `Error` for `std::io::Error`.
+fn get_type_ident(ty: &syn::Type) -> Option<&Ident> {
+    if let syn::Type::Path(type_path) = ungroup_type(ty) {
+        return type_path.path.segments.last().map(|segment| &segment.ident);
+    }
+    None
+}
+
+/// Type parameters like the `U` of `Wrapper<U>` that appear in the generic arguments of a source type, and lifetimes
+/// like the `'a` of `&'a MyError`, that aren't declared on the enum, in order of appearance. A name can be a type as
+/// well as a parameter: it is a parameter when it is a single upper-case letter, or when `predicates` or the enum's
+/// `where` clause bound it, e.g. `Error: Into<u32>`, but never when the enum's fields name it as a type, which `types`
+/// lists.
+fn free_generic_params(
+    ty: &syn::Type,
+    generics: &syn::Generics,
+    predicates: &[syn::WherePredicate],
+    types: &[Ident],
+) -> Vec<syn::GenericParam> {
+    fn lifetime(lifetime: &syn::Lifetime, lifetimes: &mut Vec<syn::Lifetime>) {
+        if lifetime.ident != "static" && lifetime.ident != "_" && !lifetimes.contains(lifetime) {
+            lifetimes.push(lifetime.to_owned());
+        }
+    }
+    fn visit(ty: &syn::Type, nested: bool, params: &mut Vec<Ident>, lifetimes: &mut Vec<syn::Lifetime>) {
+        match ty {
+            syn::Type::Path(syn::TypePath { qself: None, path }) => {
+                if let Some(ident) = path.get_ident().filter(|_| nested) {
+                    if !params.contains(ident) {
+                        params.push(ident.to_owned());
+                    }
+                    return;
+                }
+                for segment in path.segments.iter() {
+                    if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                        for argument in arguments.args.iter() {
+                            match argument {
+                                syn::GenericArgument::Type(ty) => visit(ty, true, params, lifetimes),
+                                syn::GenericArgument::Lifetime(argument) => lifetime(argument, lifetimes),
+                                _ => {},
+                            }
+                        }
+                    }
+                }
+            },
+            syn::Type::Reference(syn::TypeReference {
+                lifetime: reference,
+                elem,
+                ..
+            }) => {
+                if let Some(reference) = reference {
+                    lifetime(reference, lifetimes);
+                }
+                visit(elem, true, params, lifetimes)
+            },
+            syn::Type::Slice(syn::TypeSlice { elem, .. })
+            | syn::Type::Array(syn::TypeArray { elem, .. })
+            | syn::Type::Ptr(syn::TypePtr { elem, .. }) => visit(elem, true, params, lifetimes),
+            syn::Type::Paren(syn::TypeParen { elem, .. }) | syn::Type::Group(syn::TypeGroup { elem, .. }) => {
+                visit(elem, nested, params, lifetimes)
+            },
+            syn::Type::Tuple(tuple) => tuple.elems.iter().for_each(|elem| visit(elem, true, params, lifetimes)),
+            _ => {},
+        }
+    }
+    let mut params = vec![];
+    let mut lifetimes = vec![];
+    // The source itself is never a free parameter: `From<U>` would overlap with `From<T> for T`.
+    visit(ty, false, &mut params, &mut lifetimes);
+    let bounded: Vec<_> = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .chain(predicates)
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(syn::PredicateType {
+                bounded_ty: syn::Type::Path(syn::TypePath { qself: None, path }),
+                ..
+            }) => path.get_ident(),
+            _ => None,
+        })
+        .collect();
+    params.retain(|param| {
+        let name = param.to_string();
+        let single_letter = name.len() == 1 && name.chars().all(|c| c.is_ascii_uppercase());
+        (single_letter || bounded.contains(&param))
+            && !types.contains(param)
+            && !generics.type_params().any(|declared| &declared.ident == param)
+    });
+    lifetimes.retain(|lifetime| !generics.lifetimes().any(|declared| &declared.lifetime == lifetime));
+    lifetimes
+        .into_iter()
+        .map(|lifetime| syn::parse_quote!(#lifetime))
+        .chain(params.into_iter().map(|param| syn::parse_quote!(#param)))
+        .collect()
+}
+
+/// The names of the type parameters among `params`.
+fn type_param_idents(params: &[syn::GenericParam]) -> Vec<Ident> {
+    params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) => Some(param.ident.to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether `predicate` mentions any of `idents`.
+fn names_any(predicate: &syn::WherePredicate, idents: &[Ident]) -> bool {
+    let mut named = vec![];
+    token_idents(predicate.to_token_stream(), &mut named);
+    named.iter().any(|ident| idents.contains(ident))
+}
+
+/// Every identifier in `tokens`, including those inside groups.
+fn token_idents(tokens: proc_macro2::TokenStream, idents: &mut Vec<Ident>) {
+    for token in tokens {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) if !idents.contains(&ident) => idents.push(ident),
+            proc_macro2::TokenTree::Group(group) => token_idents(group.stream(), idents),
+            _ => {},
+        }
+    }
+}
+
+/// The number of elements of a tuple expression, looking through parentheses and blocks ending in one.
+fn tuple_arity(expr: &syn::Expr) -> Option<usize> {
+    match expr {
+        syn::Expr::Tuple(tuple) => Some(tuple.elems.len()),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) => tuple_arity(expr),
+        syn::Expr::Block(syn::ExprBlock { block, .. }) => match block.stmts.last() {
+            Some(syn::Stmt::Expr(expr)) => tuple_arity(expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether a `via` expression builds the whole enum, like `Self::Http { status: err.status }`, rather than the
+/// variant's field.
+fn builds_enum(expr: &syn::Expr, enum_name: &Ident) -> bool {
+    let names_enum = |path: &syn::Path| {
+        path.segments.len() > 1
+            && path
+                .segments
+                .first()
+                .is_some_and(|segment| segment.ident == "Self" || &segment.ident == enum_name)
+    };
+    match expr {
+        syn::Expr::Struct(syn::ExprStruct { path, .. }) | syn::Expr::Path(syn::ExprPath { path, .. }) => {
+            names_enum(path)
+        },
+        syn::Expr::Call(syn::ExprCall { func, .. }) => {
+            matches!(&**func, syn::Expr::Path(syn::ExprPath { path, .. }) if names_enum(path))
+        },
+        // A closure builds the enum if its body does, whatever it names its argument.
+        syn::Expr::Closure(syn::ExprClosure { body, .. }) => builds_enum(body, enum_name),
+        _ => false,
+    }
+}
+
+/// The `T` and `E` of a `Result<T, E>`.
+fn get_result_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
+    if let syn::Type::Path(type_path) = ungroup_type(ty) {
+        let segment = type_path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == "Result")?;
+        if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+            let mut types = arguments.args.iter().filter_map(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+            return Some((types.next()?, types.next()?));
+        }
+    }
+    None
+}
+
+/// Whether `ty` is an associated type of one of the enum's type parameters, like `T::Error` or
+/// `<T as Trait>::Error`.
+fn is_type_param_projection(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    let is_type_param = |ident: &Ident| generics.type_params().any(|param| &param.ident == ident);
+    match ungroup_type(ty) {
+        syn::Type::Path(syn::TypePath { qself: Some(qself), .. }) => {
+            get_type_ident(&qself.ty).is_some_and(is_type_param)
+        },
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            path.segments.len() > 1
+                && path
+                    .segments
+                    .first()
+                    .is_some_and(|segment| is_type_param(&segment.ident))
+        },
+        _ => false,
+    }
+}
+
+/// Values for the fields following the first one of a tuple variant, which can only be `()` or `PhantomData`.
+fn trailing_field_fillers(fields: &syn::Fields) -> Result<Vec<proc_macro2::TokenStream>, syn::Error> {
+    let mut fillers = vec![];
+    if let syn::Fields::Unnamed(fields_unnamed) = fields {
+        for field in fields_unnamed.unnamed.iter().skip(1) {
+            match zero_sized_filler(&field.ty) {
+                Some(filler) => fillers.push(filler),
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        &field.ty,
+                        "only `()` and `PhantomData` fields can follow the converted field",
+                    ))
+                },
+            }
+        }
+    }
+    Ok(fillers)
+}
+
+/// The value of a `()` or `PhantomData` field, which holds nothing the source could provide.
+fn zero_sized_filler(ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+    match ty {
+        syn::Type::Tuple(tuple) if tuple.elems.is_empty() => Some(quote!(())),
+        syn::Type::Path(type_path)
+            if type_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "PhantomData") =>
+        {
+            Some(quote!(::core::marker::PhantomData))
+        },
+        _ => None,
+    }
+}
+
+fn has_named_fields(fields: &syn::Fields, names: &[&str]) -> bool {
+    if let syn::Fields::Named(fields_named) = fields {
+        return names.iter().all(|name| {
+            fields_named
+                .named
+                .iter()
+                .any(|field| field.ident.as_ref().is_some_and(|ident| ident == name))
+        });
+    }
+    false
+}
+
+/// Converts a `CamelCase` type name into `snake_case`, keeping acronyms together (`HTTPError` -> `http_error`).
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            let starts_word = match prev {
+                Some(prev) => {
+                    prev.is_lowercase()
+                        || prev.is_ascii_digit()
+                        || (prev.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+                },
+                None => false,
+            };
+            if starts_word {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn get_enum_options(attrs: &[syn::Attribute]) -> Result<EnumOptions, syn::Error> {
+    let mut options = EnumOptions::default();
+    for attribute in attrs
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("enum_from_variant"))
+    {
+        let items = attribute.parse_args_with(Punctuated::<EnumAttributeItem, Comma>::parse_terminated)?;
+        for item in items {
+            match item {
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))) => {
+                    let key = name_value
+                        .path
+                        .get_ident()
+                        .map(|ident| ident.to_string())
+                        .unwrap_or_default();
+                    match (key.as_str(), &name_value.lit) {
+                        ("method_style", syn::Lit::Str(str)) => {
+                            options.method_style = match str.value().as_str() {
+                                "snake" => MethodStyle::Snake,
+                                "lowercase" => MethodStyle::Lowercase,
+                                _ => return Err(syn::Error::new(str.span(), "expected `snake` or `lowercase`")),
+                            }
+                        },
+                        ("method_prefix", syn::Lit::Str(str)) => options.method_prefix = Some(str.value()),
+                        ("vis", syn::Lit::Str(str)) => options.vis = Some(str.parse()?),
+                        ("bound", syn::Lit::Str(str)) => options.bounds.push(str.parse()?),
+                        ("crate", syn::Lit::Str(str)) => options.krate = Some(str.parse()?),
+                        ("module", syn::Lit::Str(str)) => options.module = Some(str.parse()?),
+                        ("param", syn::Lit::Str(str)) => options.param = Some(str.parse()?),
+                        (key, _) => return Err(misused_enum_option(&name_value, key)),
+                    }
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path))) if path.is_ident("strict") => {
+                    options.strict = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path))) if path.is_ident("debug") => {
+                    options.debug = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                    if path.is_ident("emit_count") =>
+                {
+                    if !cfg!(feature = "testing") {
+                        return Err(syn::Error::new_spanned(
+                            path,
+                            "`emit_count` requires the `testing` feature",
+                        ));
+                    }
+                    options.emit_count = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                    if path.is_ident("transparent") =>
+                {
+                    options.transparent = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path))) if path.is_ident("deref") => {
+                    options.deref = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                    if path.is_ident("display_delegate") =>
+                {
+                    options.display_delegate = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path))) if path.is_ident("doc_links") => {
+                    options.doc_links = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                    if path.is_ident("assert_impls") =>
+                {
+                    options.assert_impls = true
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(syn::Meta::Path(path)))
+                    if path.is_ident("validate_only") =>
+                {
+                    options.validate_only = true
+                },
+                EnumAttributeItem::Block(key, tokens) if key == "io_kind" => {
+                    let arms = Punctuated::<IoKindArm, Comma>::parse_terminated.parse2(tokens)?;
+                    options.io_kind = Some(IoKind {
+                        key,
+                        arms: arms.into_iter().collect(),
+                    });
+                },
+                EnumAttributeItem::Block(key, tokens) if key == "bidirectional" => {
+                    options.bidirectional.push(syn::parse2(tokens)?);
+                },
+                EnumAttributeItem::Block(key, tokens) if key == "table" => {
+                    options
+                        .table
+                        .extend(Punctuated::<TableEntry, Comma>::parse_terminated.parse2(tokens)?);
+                },
+                EnumAttributeItem::Routed(key, tokens, variant_ident) if key == "box_all" => {
+                    let sources = Punctuated::<syn::LitStr, Comma>::parse_terminated.parse2(tokens)?;
+                    options.box_all.push(BoxAll {
+                        sources: sources.into_iter().collect(),
+                        variant_ident,
+                    });
+                },
+                EnumAttributeItem::Keyed(key, source, arms) if key == "group" => {
+                    options.groups.push(Group::parse(source, arms)?);
+                },
+                EnumAttributeItem::Keyed(key, variant_ident, arms) if key == "flatten" => {
+                    options.flatten.push(Flatten::parse(variant_ident, arms)?);
+                },
+                EnumAttributeItem::Block(key, _)
+                | EnumAttributeItem::Routed(key, ..)
+                | EnumAttributeItem::Keyed(key, ..) => {
+                    return Err(misused_enum_option(&key, &key.to_string()));
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Meta(meta)) => {
+                    let key = meta
+                        .path()
+                        .get_ident()
+                        .map(|ident| ident.to_string())
+                        .unwrap_or_default();
+                    return Err(misused_enum_option(&meta, &key));
+                },
+                EnumAttributeItem::Meta(syn::NestedMeta::Lit(lit)) => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "expected an option: sources are declared on variants, or with `#[enum_from(..)]`",
+                    ));
+                },
+            }
+        }
+    }
+    Ok(options)
+}
+
+fn map_enum_data_from_variant(variants: &Punctuated<syn::Variant, Comma>) -> Result<Vec<MapEnumData<'_>>, syn::Error> {
+    let mut meta_vec = vec![];
+    for variant in variants.iter() {
+        let Some(attr) = get_attributes(variant)? else {
+            continue;
+        };
+        // A source listed twice, in one attribute or across several, would generate the same impl twice, so later
+        // copies are dropped.
+        let mut sources = vec![];
+        for (index, nested_meta) in attr.nested_metas.iter().enumerate() {
+            let mut options = get_conversion_options(nested_meta)?;
+            let mut conversion = |meta: syn::NestedMeta, options: &ConversionOptions, from_field| {
+                if let syn::NestedMeta::Lit(syn::Lit::Str(str)) = &meta {
+                    let source = (
+                        normalized_source(str),
+                        options.claims_source(),
+                        options.cfg_attrs().to_string(),
+                    );
+                    if sources.contains(&source) {
+                        return None;
+                    }
+                    sources.push(source);
+                }
+                Some(MapEnumData {
+                    variant_ident: attr.variant_ident.to_owned(),
+                    meta,
+                    inner_ident: attr.inner_ident.clone(),
+                    fields: attr.fields,
+                    deprecated: attr.deprecated,
+                    options: options.clone(),
+                    from_field,
+                })
+            };
+            // The marked field belongs to the variant, so only the first attribute's options apply to it.
+            let from_field = attr.from_field.filter(|_| index == 0);
+            // A marked tuple field is the same as `from` on the variant.
+            if from_field.as_ref().is_some_and(|field| field.ident.is_none()) {
+                options.from = true;
+            }
+            if let Some(field) = from_field.filter(|field| field.ident.is_some()) {
+                let meta = syn::NestedMeta::Lit(syn::Lit::Str(syn::LitStr::new(
+                    &field.ty.to_token_stream().to_string(),
+                    field.ty.span(),
+                )));
+                meta_vec.extend(conversion(meta, &options, Some(field)));
+            }
+            if options.from {
+                let field = get_variant_unnamed_type(attr.fields).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &attr.variant_ident,
+                        "`from` requires a tuple variant to infer the source type from",
+                    )
+                })?;
+                let meta = syn::NestedMeta::Lit(syn::Lit::Str(syn::LitStr::new(
+                    &field.to_token_stream().to_string(),
+                    field.span(),
+                )));
+                meta_vec.extend(conversion(meta, &options, None));
+            }
+            if let Some(from_variant) = &options.from_variant {
+                let variant = variants
+                    .iter()
+                    .find(|variant| &variant.ident == from_variant)
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(from_variant, format!("no variant named `{}`", from_variant))
+                    })?;
+                let field = source_field(&variant.fields).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        from_variant,
+                        format!("`{}` holds no field to convert from", from_variant),
+                    )
+                })?;
+                let meta = syn::NestedMeta::Lit(syn::Lit::Str(syn::LitStr::new(
+                    &field.ty.to_token_stream().to_string(),
+                    from_variant.span(),
+                )));
+                meta_vec.extend(conversion(meta, &options, None));
+            }
+            for meta in nested_meta.iter() {
+                meta_vec.extend(conversion(meta.clone(), &options, None));
+            }
+        }
+    }
+    Ok(meta_vec)
+}
+
+/// A source type as written in an attribute, normalized so that spacing doesn't tell two copies apart.
+fn normalized_source(str: &syn::LitStr) -> String {
+    str.parse::<syn::Type>()
+        .map_or_else(|_| str.value(), |ty| ty.to_token_stream().to_string())
+}
+
+/// One `Variant <- Source` entry of the enum-level `#[enum_from(..)]` attribute.
+struct BatchConversion {
+    variant_ident: Ident,
+    source: syn::Type,
+}
+
+/// An entry of `enum_conversions!`.
+enum ConversionsEntry {
+    Newtype(Newtype),
+    FanOut(FanOut),
+}
+
+/// A `#[derive(..)] pub newtype Wrapper(Enum)` entry of `enum_conversions!`, declaring a local wrapper around an enum
+/// of another crate for `Wrapper(Enum::Variant)` targets.
+struct Newtype {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    ident: Ident,
+    inner: syn::Type,
+}
+
+/// One `Source => (EnumA::Variant, EnumB::Variant)` entry of `enum_conversions!`.
+struct FanOut {
+    source: syn::Type,
+    targets: Punctuated<FanOutTarget, Comma>,
+}
+
+/// `Enum::Variant`, or `Wrapper(Enum::Variant)` for a local newtype around an enum of another crate, which can't have
+/// the `From` impl itself.
+struct FanOutTarget {
+    wrapper: Option<syn::Path>,
+    variant: syn::Path,
+}
+
+impl Parse for ConversionsEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis: syn::Visibility = input.parse()?;
+        // A source type may be named `newtype` too, but isn't followed by another name.
+        let is_newtype =
+            input.peek(syn::Ident) && input.peek2(syn::Ident) && input.fork().parse::<Ident>()? == "newtype";
+        if !is_newtype {
+            if !attrs.is_empty() || !matches!(vis, syn::Visibility::Inherited) {
+                return Err(input.error("expected `newtype Wrapper(Enum)`"));
+            }
+            return input.parse().map(ConversionsEntry::FanOut);
+        }
+        input.parse::<Ident>()?;
+        let ident = input.parse()?;
+        let content;
+        syn::parenthesized!(content in input);
+        Ok(ConversionsEntry::Newtype(Newtype {
+            attrs,
+            vis,
+            ident,
+            inner: content.parse()?,
+        }))
+    }
+}
+
+impl Parse for FanOut {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let source = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        Ok(FanOut {
+            source,
+            targets: content.parse_terminated(FanOutTarget::parse)?,
+        })
+    }
+}
+
+impl Parse for FanOutTarget {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        if !input.peek(syn::token::Paren) {
+            return Ok(FanOutTarget {
+                wrapper: None,
+                variant: path,
+            });
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        Ok(FanOutTarget {
+            wrapper: Some(path),
+            variant: content.parse()?,
+        })
+    }
+}
+
+impl Parse for BatchConversion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let variant_ident = input.parse()?;
+        input.parse::<Token![<-]>()?;
+        let source = input.parse()?;
+        Ok(BatchConversion { variant_ident, source })
+    }
+}
+
+/// Reads the conversions listed in `#[enum_from(Variant <- Source, ..)]` on the enum itself.
+fn map_enum_data_from_batch<'a>(
+    attrs: &[syn::Attribute],
+    variants: &'a Punctuated<syn::Variant, Comma>,
+) -> Result<Vec<MapEnumData<'a>>, syn::Error> {
+    let mut meta_vec = vec![];
+    for attribute in attrs.iter().filter(|attribute| attribute.path.is_ident("enum_from")) {
+        let conversions = attribute.parse_args_with(Punctuated::<BatchConversion, Comma>::parse_terminated)?;
+        for conversion in conversions {
+            let source = syn::LitStr::new(
+                &conversion.source.to_token_stream().to_string(),
+                conversion.source.span(),
+            );
+            meta_vec.push(enum_level_conversion(variants, &conversion.variant_ident, source)?);
+        }
+    }
+    Ok(meta_vec)
+}
+
+/// A conversion declared on the enum itself into `variant_ident`, with default options.
+fn enum_level_conversion<'a>(
+    variants: &'a Punctuated<syn::Variant, Comma>,
+    variant_ident: &Ident,
+    source: syn::LitStr,
+) -> Result<MapEnumData<'a>, syn::Error> {
+    let variant = variants
+        .iter()
+        .find(|variant| &variant.ident == variant_ident)
+        .ok_or_else(|| syn::Error::new_spanned(variant_ident, format!("no variant named `{}`", variant_ident)))?;
+    Ok(MapEnumData {
+        variant_ident: variant.ident.to_owned(),
+        meta: syn::NestedMeta::Lit(syn::Lit::Str(source)),
+        inner_ident: get_variant_unnamed_ident(&variant.fields),
+        fields: &variant.fields,
+        deprecated: is_deprecated(&variant.attrs),
+        options: ConversionOptions::default(),
+        from_field: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn get_attributes_skips_variant_without_attribute() {
+        let variant: syn::Variant = parse_quote!(Network(String));
+        assert!(get_attributes(&variant).unwrap().is_none());
+    }
+
+    #[test]
+    fn get_attributes_ignores_unrelated_attributes() {
+        let variant: syn::Variant = parse_quote! {
+            #[allow(dead_code)]
+            Network(String)
+        };
+        assert!(get_attributes(&variant).unwrap().is_none());
+    }
+
+    #[test]
+    fn get_attributes_skips_doc_comments() {
+        let variant: syn::Variant = parse_quote! {
+            /// The network is down.
+            #[enum_from_variant("NetworkError")]
+            /// Retry later.
+            Network(String)
+        };
+        let attr = get_attributes(&variant).unwrap().unwrap();
+        assert_eq!(attr.nested_metas[0].len(), 1);
+    }
+
+    #[test]
+    fn get_attributes_reads_attribute() {
+        let variant: syn::Variant = parse_quote! {
+            #[enum_from_variant("NetworkError")]
+            Network(String)
+        };
+        let attr = get_attributes(&variant).unwrap().unwrap();
+        assert_eq!(attr.variant_ident, "Network");
+        assert_eq!(attr.nested_metas[0].len(), 1);
+    }
+
+    #[test]
+    fn get_attributes_rejects_malformed_attribute() {
+        let variant: syn::Variant = parse_quote! {
+            #[enum_from_variant = "NetworkError"]
+            Network(String)
+        };
+        let err = get_attributes(&variant).unwrap_err();
+        assert_eq!(err.to_string(), "expected #[enum_from_variant(..)]");
+    }
+
+    #[test]
+    fn expand_scopes_helpers_in_anonymous_const() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("DbError", ext)]
+                Database(DbError),
+                #[enum_from_variant("NetworkError")]
+                Network(String),
+            }
+        })
+        .to_string();
+        let (module_items, helpers) = expanded.split_once("const _ : () =").unwrap();
+        assert!(module_items.starts_with("impl :: core :: convert :: From < NetworkError > for MainError"));
+        assert!(module_items.contains("trait MainErrorFromExt"));
+        assert!(!module_items.contains("impl MainErrorFromExt"));
+        assert!(helpers.contains("impl MainErrorFromExt for MainError"));
+        assert!(!helpers.contains(":: core :: convert :: From"));
+    }
+
+    #[test]
+    fn expand_handles_many_variants() {
+        let mut source = String::from("enum MainError {\n");
+        for i in 0..200 {
+            source += &format!(
+                "#[enum_from_variant(\"Source{0}\", \"Other{0}\")]\nV{0}(Source{0}),\n",
+                i
+            );
+        }
+        source += "}";
+        let expanded = expand(syn::parse_str(&source).unwrap()).to_string();
+        let impls: Vec<_> = expanded
+            .split("impl ")
+            .skip(1)
+            .map(|item| item.split(" for ").next().unwrap())
+            .collect();
+        assert_eq!(impls.len(), 400);
+        assert_eq!(impls[0], ":: core :: convert :: From < Source0 >");
+        assert_eq!(impls[399], ":: core :: convert :: From < Other199 >");
+    }
+
+    #[test]
+    fn generated_conversions_return_self() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(io_kind { _ => Io })]
+            enum MainError<T> {
+                #[enum_from_variant("DbError", also_boxed)]
+                Database(String),
+                #[enum_from_variant("String", also_str)]
+                Message(String),
+                #[enum_from_variant("u64", try)]
+                Code(u8),
+                #[enum_from_variant("Result<u8, ()>", ok)]
+                Ok(u8),
+                Io(std::io::Error),
+                #[enum_from_variant(blanket)]
+                Value(T),
+            }
+        })
+        .to_string();
+        let signatures: Vec<_> = expanded
+            .split("fn ")
+            .skip(1)
+            .map(|item| item.split(" {").next().unwrap())
+            .collect();
+        assert_eq!(
+            signatures,
+            [
+                "from (err : DbError) -> Self",
+                "from (err : :: std :: boxed :: Box < DbError >) -> Self",
+                "from (err : & str) -> Self",
+                "from (err : String) -> Self",
+                "try_from (err : u64) -> :: core :: result :: Result < Self , Self :: Error >",
+                "try_from (result : Result < u8 , () >) -> :: core :: result :: Result < Self , Self :: Error >",
+                "from (err : __EnumFromVariantSource) -> Self",
+                "from (err : :: std :: io :: Error) -> Self",
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_handles_elided_lifetimes_in_fields() {
+        // Invalid Rust, left to the compiler to report, but inspecting it must not panic.
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("Ref<'_>", accessors)]
+                R(Ref<'_>),
+                #[enum_from_variant("&str")]
+                S(&'_ str),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains(":: core :: convert :: From < Ref < '_ > > for MainError"));
+    }
+
+    #[test]
+    fn expand_emits_impls_in_source_order() {
+        let expanded = expand(parse_quote! {
+            #[enum_from(Timeout <- Elapsed)]
+            enum MainError {
+                #[enum_from_variant("NetworkError", "std::fmt::Error")]
+                Network(String),
+                #[enum_from_variant("DbError")]
+                Database(DbError),
+                Timeout(Elapsed),
+                #[enum_from_variant("u64", try)]
+                Code(u8),
+            }
+        })
+        .to_string();
+        let impls: Vec<_> = expanded
+            .split("impl ")
+            .skip(1)
+            .map(|item| item.split(" for ").next().unwrap())
+            .collect();
+        // Variant attributes come first, in declaration order, followed by `#[enum_from(..)]` entries.
+        assert_eq!(
+            impls,
+            [
+                ":: core :: convert :: From < NetworkError >",
+                ":: core :: convert :: From < std :: fmt :: Error >",
+                ":: core :: convert :: From < DbError >",
+                ":: core :: convert :: TryFrom < u64 >",
+                ":: core :: convert :: From < Elapsed >",
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_drops_duplicate_sources_across_attributes() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("DbError")]
+                #[enum_from_variant("DbError", "NetworkError")]
+                #[enum_from_variant("DbError", ext)]
+                Database(String),
+            }
+        })
+        .to_string();
+        assert_eq!(
+            expanded.matches("impl :: core :: convert :: From < DbError >").count(),
+            1
+        );
+        assert_eq!(
+            expanded
+                .matches("impl :: core :: convert :: From < NetworkError >")
+                .count(),
+            1
+        );
+        assert!(expanded.contains("fn from_db_error"));
+    }
+
+    #[test]
+    fn expand_drops_duplicate_sources_of_a_variant() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("std::io::Error", "std :: io :: Error", "DbError")]
+                Io(String),
+            }
+        })
+        .to_string();
+        assert_eq!(
+            expanded
+                .matches("impl :: core :: convert :: From < std :: io :: Error >")
+                .count(),
+            1
+        );
+        assert_eq!(
+            expanded.matches("impl :: core :: convert :: From < DbError >").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn vis_applies_to_generated_methods_and_traits() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(vis = "pub(crate)")]
+            pub enum MainError {
+                #[enum_from_variant("DbError", accessors)]
+                Database(DbError),
+                #[enum_from_variant("NetworkError", ext)]
+                Network(String),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains("pub (crate) fn is_database"));
+        assert!(expanded.contains("pub (crate) fn as_database"));
+        assert!(expanded.contains("pub (crate) trait MainErrorFromExt"));
+    }
+
+    #[test]
+    fn param_renames_the_source_everywhere() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(param = "source")]
+            enum MainError {
+                #[enum_from_variant("i32", via = "source.abs()")]
+                Code(i32),
+                #[enum_from_variant("u64", try)]
+                Small(u8),
+                #[enum_from_variant("DbError", ext)]
+                Database(DbError),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains("fn from (source : i32) -> Self { MainError :: Code (source . abs ()) }"));
+        assert!(expanded.contains("fn try_from (source : u64)"));
+        assert!(expanded.contains("try_into (source)"));
+        assert!(expanded.contains("fn from_db_error (source : DbError) -> Self { MainError :: Database (source) }"));
+        assert!(!expanded
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|word| word == "err"));
+    }
+
+    #[test]
+    fn cold_marks_generated_conversions() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("DbError", cold)]
+                Database(DbError),
+                #[enum_from_variant("u64", try, cold, track_caller)]
+                Small(u8),
+                #[enum_from_variant("NetworkError")]
+                Network(NetworkError),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains("# [cold] fn from (err : DbError)"));
+        assert!(expanded.contains("# [track_caller] # [cold] fn try_from (err : u64)"));
+        assert!(!expanded.contains("# [cold] fn from (err : NetworkError)"));
+    }
+
+    #[test]
+    fn doc_links_document_generated_impls() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(doc_links)]
+            enum MainError {
+                #[enum_from_variant("DbError", "&'static str")]
+                Database(String),
+                #[enum_from_variant("u64", try)]
+                Small(u8),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains(r#"# [doc = "Converts [`DbError`] into [`MainError::Database`]."]"#));
+        assert!(expanded.contains(r#"# [doc = "Converts `&'static str` into [`MainError::Database`]."]"#));
+        assert!(expanded.contains(r#"# [doc = "Converts [`u64`] into [`MainError::Small`]."]"#));
+        let plain = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("DbError")]
+                Database(DbError),
+            }
+        })
+        .to_string();
+        assert!(!plain.contains("# [doc"));
+    }
+
+    #[test]
+    fn assert_impls_checks_each_conversion() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(assert_impls)]
+            enum MainError {
+                #[enum_from_variant("DbError", feature = "db")]
+                Database(DbError),
+                #[enum_from_variant("u64", try)]
+                Small(u8),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains(
+            "# [cfg (feature = \"db\")] const _ : () = { fn assert_impl < __EnumFromVariantSource , __EnumFromVariantTarget : :: core :: convert :: From < __EnumFromVariantSource >"
+        ));
+        assert!(expanded.contains("assert_impl :: < DbError , MainError > ()"));
+        assert!(expanded.contains(":: core :: convert :: TryFrom < __EnumFromVariantSource >"));
+        assert!(expanded.contains("assert_impl :: < u64 , MainError > ()"));
+    }
+
+    #[test]
+    fn validate_only_keeps_errors_and_drops_items() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(validate_only)]
+            enum MainError {
+                #[enum_from_variant("DbError", ext, accessors)]
+                Database(DbError),
+                #[enum_from_variant("MainError")]
+                Nested(Box<MainError>),
+                #[enum_from_variant("NetworkError", also_str)]
+                Network(NetworkError),
+                #[enum_from_variant("NetworkError")]
+                Retry(NetworkError),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains("Cannot convert an enum into itself"));
+        assert!(expanded.contains("`also_str` requires a `String` field"));
+        assert!(expanded.contains("`NetworkError` is already converted into `Network`, so it can't also go to `Retry`"));
+        assert!(!expanded.contains("impl "));
+        assert!(!expanded.contains("trait "));
+        let valid = expand(parse_quote! {
+            #[enum_from_variant(validate_only)]
+            enum MainError {
+                #[enum_from_variant("DbError")]
+                Database(DbError),
+            }
+        });
+        assert!(valid.is_empty());
+    }
+
+    #[test]
+    fn move_skips_to_string_on_string_fields() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("String", move)]
+                Message(String),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains("MainError :: Message (err)"));
+        assert!(!expanded.contains("to_string"));
+    }
+
+    #[test]
+    fn test_only_gates_generated_conversions() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("MockError", test_only)]
+                Mock(MockError),
+                #[enum_from_variant("DbError", test_only, feature = "db")]
+                Database(DbError),
+                #[enum_from_variant("NetworkError")]
+                Network(NetworkError),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains("# [cfg (test)] impl :: core :: convert :: From < MockError >"));
+        assert!(
+            expanded.contains("# [cfg (feature = \"db\")] # [cfg (test)] impl :: core :: convert :: From < DbError >")
+        );
+        assert!(!expanded.contains("# [cfg (test)] impl :: core :: convert :: From < NetworkError >"));
+    }
+
+    #[test]
+    fn track_caller_marks_generated_conversions() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("i32", via = "err.abs()", track_caller)]
+                Code(i32),
+                #[enum_from_variant("u64", try, track_caller)]
+                Small(u8),
+                #[enum_from_variant("DbError")]
+                Database(DbError),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains("# [track_caller] fn from (err : i32)"));
+        assert!(expanded.contains("# [track_caller] fn try_from (err : u64)"));
+        assert!(!expanded.contains("# [track_caller] fn from (err : DbError)"));
+    }
+
+    #[test]
+    fn bound_is_added_to_every_impl() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(bound = "Self: Send")]
+            enum MainError<T> where T: Clone {
+                #[enum_from_variant("DbError", accessors)]
+                Database(DbError),
+                #[enum_from_variant("String", also_str)]
+                Message(String),
+                #[enum_from_variant("u64", try)]
+                Code(u8),
+                #[enum_from_variant("NetworkError", ext)]
+                Network(T),
+            }
+        })
+        .to_string();
+        assert_eq!(expanded.matches("impl <").count(), 6);
+        // The ext trait carries the enum's bounds too.
+        assert_eq!(expanded.matches("where T : Clone , Self : Send").count(), 7);
+        assert!(expanded.contains("trait MainErrorFromExt < T > where T : Clone , Self : Send"));
+    }
+
+    #[test]
+    fn bound_can_name_free_source_params() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(bound = "U: Into<T>")]
+            enum LiftedError<T> {
+                #[enum_from_variant("Wrapper<U>", via = "err.0.into()")]
+                Value(T),
+                #[enum_from_variant("u8", accessors)]
+                Code(u8),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains(
+            "impl < T , U > :: core :: convert :: From < Wrapper < U > > for LiftedError < T > where U : Into < T >"
+        ));
+        // The other impls don't declare `U`, so they don't get the bound.
+        assert!(expanded.contains("impl < T > :: core :: convert :: From < u8 > for LiftedError < T > {"));
+        assert!(expanded.contains("impl < T > LiftedError < T > {"));
+        assert_eq!(expanded.matches("U : Into < T >").count(), 1);
+    }
+
+    #[test]
+    fn conversion_options_read_nested_lists() {
+        let variant: syn::Variant = parse_quote! {
+            #[enum_from_variant("Wrapper<E>", via("Box::new(err.0)"), where("E: Error + 'static", "E: Clone"))]
+            Boxed(Box<dyn Error>)
+        };
+        let attr = get_attributes(&variant).unwrap().unwrap();
+        let options = get_conversion_options(&attr.nested_metas[0]).unwrap();
+        assert!(options.via.is_some());
+        assert_eq!(options.predicates.len(), 2);
+
+        let variant: syn::Variant = parse_quote! {
+            #[enum_from_variant("DbError", where(Clone))]
+            Database(DbError)
+        };
+        let attr = get_attributes(&variant).unwrap().unwrap();
+        let err = get_conversion_options(&attr.nested_metas[0]).unwrap_err();
+        assert_eq!(err.to_string(), "expected a literal, as in `where = ..`");
+    }
+
+    #[test]
+    fn conversion_where_applies_to_its_impl_only() {
+        let expanded = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("Wrapper<E>", via = "Box::new(err.0)", where = "E: Error + Send + 'static, E: Clone")]
+                Report(BoxError),
+                #[enum_from_variant("u8")]
+                Code(u8),
+            }
+        })
+        .to_string();
+        assert_eq!(expanded.matches("where").count(), 1);
+        assert!(expanded.contains("for MainError where E : Error + Send + 'static , E : Clone {"));
+    }
+
+    #[test]
+    fn debug_does_not_change_generated_code() {
+        let plain = expand(parse_quote! {
+            enum MainError {
+                #[enum_from_variant("DbError", accessors)]
+                Database(DbError),
+            }
+        });
+        let debugged = expand(parse_quote! {
+            #[enum_from_variant(debug)]
+            enum MainError {
+                #[enum_from_variant("DbError", accessors)]
+                Database(DbError),
+            }
+        });
+        assert_eq!(plain.to_string(), debugged.to_string());
+    }
+
+    #[test]
+    fn crate_path_replaces_the_default() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(crate = "::my_errors::conversions")]
+            enum MainError {
+                #[enum_from_variant("u32", try)]
+                Code(u8),
+            }
+        })
+        .to_string();
+        assert!(expanded.contains(":: my_errors :: conversions :: ConversionError"));
+        assert!(!expanded.contains(":: enum_from_variant"));
+    }
+
+    #[test]
+    fn free_generic_params_skip_concrete_instantiations() {
+        let generics: syn::Generics = parse_quote!(<'a, T>);
+        let predicates: Vec<syn::WherePredicate> = vec![parse_quote!(Input: Clone), parse_quote!(T: From<Error>)];
+        let types = [format_ident!("E")];
+        let free = |ty: syn::Type| {
+            free_generic_params(&ty, &generics, &predicates, &types)
+                .iter()
+                .map(|param| param.to_token_stream().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert!(free(parse_quote!(Wrapper<ConcreteErr>)).is_empty());
+        // A field holds an `E`, so it's a type.
+        assert!(free(parse_quote!(Wrapper<E>)).is_empty());
+        assert!(free(parse_quote!(Wrapper<errors::U>)).is_empty());
+        // `Error` is only named in a bound, so it's a type like any other, while `Input` is bounded itself.
+        assert!(free(parse_quote!(Wrapper<Error>)).is_empty());
+        assert_eq!(free(parse_quote!(Wrapper<Input, V>)), ["Input", "V"]);
+        assert!(free(parse_quote!(Wrapper<'a, T>)).is_empty());
+        assert!(free(parse_quote!(&'static str)).is_empty());
+        assert_eq!(free(parse_quote!(Wrapper<ConcreteErr, U>)), ["U"]);
+        assert_eq!(free(parse_quote!(&'b Wrapper<U>)), ["'b", "U"]);
+        assert!(free(parse_quote!(for<'b> fn(&'b str) -> usize)).is_empty());
+        assert!(free(parse_quote!(std::collections::HashMap<String, Vec<Option<u8>>>)).is_empty());
+        assert_eq!(
+            free(parse_quote!(std::collections::HashMap<String, Vec<Option<U>>>)),
+            ["U"]
+        );
+    }
+
+    #[test]
+    fn std_converts_into_knows_std_conversions() {
+        let converts = |source: syn::Type, field: syn::Type| std_converts_into(&source, &field);
+        assert!(converts(parse_quote!(String), parse_quote!(String)));
+        assert!(converts(parse_quote!(&'static str), parse_quote!(String)));
+        assert!(converts(parse_quote!(char), parse_quote!(std::string::String)));
+        assert!(converts(parse_quote!(Cow<'static, str>), parse_quote!(String)));
+        assert!(converts(
+            parse_quote!(&'static str),
+            parse_quote!(Box<dyn Error + Send>)
+        ));
+        assert!(converts(parse_quote!(DbError), parse_quote!(Box<DbError>)));
+        assert!(converts(parse_quote!([u8; 4]), parse_quote!(Box<[u8]>)));
+        assert!(converts(parse_quote!(&'static [u8]), parse_quote!(Vec<u8>)));
+        assert!(converts(parse_quote!(VecDeque<u8>), parse_quote!(Vec<u8>)));
+        assert!(converts(parse_quote!(String), parse_quote!(Vec<u8>)));
+        // Whether other types implement `Into` is left to rustc.
+        assert!(!converts(parse_quote!(DbError), parse_quote!(String)));
+        assert!(!converts(parse_quote!(String), parse_quote!(Vec<char>)));
+        assert!(!converts(parse_quote!(&'static [u8; 4]), parse_quote!(Box<[u8]>)));
+        assert!(!converts(parse_quote!(&'static str), parse_quote!(my::String)));
+    }
+
+    #[test]
+    fn module_wraps_generated_impls() {
+        let expanded = expand(parse_quote! {
+            #[enum_from_variant(module = "conversions")]
+            enum MainError {
+                #[enum_from_variant("DbError")]
+                Database(DbError),
+            }
+        })
+        .to_string();
+        assert!(expanded.starts_with(
+            "mod conversions { use super :: * ; impl :: core :: convert :: From < DbError > for MainError"
+        ));
+    }
+
+    #[test]
+    fn get_variant_unnamed_ident_reads_the_first_field() {
+        let variant: syn::Variant = parse_quote!(Database(crate::db::DbError, u8));
+        assert_eq!(get_variant_unnamed_ident(&variant.fields).unwrap(), "DbError");
+        let variant: syn::Variant = parse_quote!(Tagged((Vec<u8>), u8));
+        assert_eq!(get_variant_unnamed_ident(&variant.fields).unwrap(), "Vec");
+        let variant: syn::Variant = parse_quote!(Elided(Ref<'_>));
+        assert_eq!(get_variant_unnamed_ident(&variant.fields).unwrap(), "Ref");
+        let variant: syn::Variant = parse_quote!(Borrowed(&'static str));
+        assert!(get_variant_unnamed_ident(&variant.fields).is_none());
+        let variant: syn::Variant = parse_quote!(Hash([u8; 32]));
+        assert!(get_variant_unnamed_ident(&variant.fields).is_none());
+        let variant: syn::Variant = parse_quote!(Http { status: u16 });
+        assert!(get_variant_unnamed_ident(&variant.fields).is_none());
+    }
+
+    #[test]
+    fn method_name_follows_enum_options() {
+        let source = format_ident!("DbError");
+        let options = EnumOptions::default();
+        assert_eq!(options.method_name("from_", &source), "from_db_error");
+        let options =
+            get_enum_options(&[parse_quote!(#[enum_from_variant(method_style = "lowercase", method_prefix = "new_")])])
+                .unwrap();
+        assert_eq!(options.method_name("from_", &source), "new_dberror");
+    }
+
+    /// Deterministic linear congruential generator, enough to drive the property test without extra dependencies.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn pick<'a>(&mut self, choices: &[&'a str]) -> &'a str {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            choices[(self.0 >> 33) as usize % choices.len()]
+        }
+    }
+
+    #[test]
+    fn expand_never_panics_on_generated_enums() {
+        const FIELDS: &[&str] = &[
+            "",
+            "(String)",
+            "(DbError)",
+            "(Vec<u8>)",
+            "(&'static str)",
+            "(fn() -> i32)",
+            "(u8, ())",
+            "(u32, u32)",
+            "(A, B)",
+            "{ message: String, source: Box<dyn Error> }",
+            "{ inner: T }",
+            "{ #[from] source: std::io::Error, path: String }",
+            "(#[convert] DbError)",
+            "(u8, #[convert] DbError)",
+            "(std::borrow::Cow<'static, str>)",
+            "((Foo))",
+            "(payload!())",
+            "([u8; 4])",
+            "(T)",
+            "(T::Error)",
+        ];
+        const ATTRIBUTES: &[&str] = &[
+            "",
+            "/// Documented.",
+            "#[deprecated]",
+            "#[enum_from_variant(\"DbError\")]",
+            "#[enum_from_variant(\"String\", \"&'static str\", also_str)]",
+            "#[enum_from_variant(\"DbError\", also_boxed)]",
+            "#[enum_from_variant(\"DbError\", count)]",
+            "#[enum_from_variant(\"Wrapper<E>\", where = \"E: Send + Sync + 'static, E: Clone\")]",
+            "#[enum_from_variant(from_variant = \"V0\")]",
+            "#[enum_from_variant(\"Wrapper<E>\", where(\"E: Send + Sync + 'static\", \"E: Clone\"))]",
+            "#[enum_from_variant(42)]",
+            "#[enum_from_variant(\"DbError\", debug_string)]",
+            "#[enum_from_variant(\"DbError\", backtrace)]",
+            "#[enum_from_variant(\"DbError\", timestamp)]",
+            "#[enum_from_variant(\"DbError\", result)]",
+            "#[enum_from_variant(\"\")]",
+            "#[enum_from_variant = \"DbError\"]",
+            "#[enum_from_variant]",
+            "#[enum_from_variant(\"Vec<\")]",
+            "#[enum_from_variant(\"T\", boxed, feature = \"db\")]",
+            "#[enum_from_variant(\"DbError\", test_only)]",
+            "#[enum_from_variant(42)]",
+            "#[enum_from_variant(\"E\")]",
+            "#[enum_from_variant(\"ThirdParty\", chain)]",
+            "#[enum_from_variant(\"u32\", try)]",
+            "#[enum_from_variant(\"Sub\", collect, ext)]",
+            "#[enum_from_variant(\"T::Error\", on_convert = \"hooks::record\")]",
+            "#[enum_from_variant(\"T\", into_turbofish = \"T\")]",
+            "#[enum_from_variant(\"i32\", via = \"err.abs()\")]",
+            "#[enum_from_variant(\"i32\", via = \"|code| code +\")]",
+            "#[enum_from_variant(\"i32\", via = \"|code, base| code\")]",
+            "#[enum_from_variant(\"Src\", via = \"err.a\", via_ref)]",
+            "#[enum_from_variant(\"Src\", via_ref)]",
+            "#[enum_from_variant(blanket)]",
+            "#[enum_from_variant(\"DbError\", \"Timeout\", accessors)]",
+            "#[enum_from_variant(from)]",
+            "#[enum_from_variant(\"DbError\", move)]",
+            "#[enum_from_variant(\"NetworkError\", stringify)]",
+            "#[enum_from_variant(\"(u32, u32)\")]",
+            "#[enum_from_variant(\"DbError\", boxed_dyn)]",
+            "#[enum_from_variant(\"DbError\", anyhow)]",
+            "#[enum_from_variant(\"Low\", through = \"Mid\")]",
+            "#[enum_from_variant(\"Low\", wrap = \"Local\")]",
+            "#[enum_from_variant(\"Local\", unwrap)]",
+            "#[enum_from_variant(\"()\")]",
+            "#[enum_from_variant(\"Result<_, DbError>\", err)]",
+            "#[enum_from_variant(\"DbError\", err)]",
+            "#[enum_from_variant(\"Result<u8, DbError>\", ok)]",
+            "#[enum_from_variant(\"u64\", try, track_caller)]",
+            "#[enum_from_variant(\"u64\", try, keep_source)]",
+            "#[enum_from_variant(\"String\", try, when = \"!err.is_empty()\")]",
+            "#[enum_from_variant(\"DbError\", cold)]",
+            "#[enum_from_variant(\"DbError\", manual, accessors)]",
+            "#[enum_from_variant(\"DbError\", into_method)]",
+            "#[enum_from_variant(\"NetworkError\", assert_display)]",
+            "#[enum_from_variant(\"NetworkError\", prefix = \"net {}: \")]",
+            "#[enum_from_variant(\"impl Into<String>\")]",
+            "#[enum_from_variant(\"Src\", via = \"|src| (src.a, src.b)\")]",
+            "#[enum_from_variant(\"Wrapper<U, T>\")]",
+            "#[enum_from_variant(\"&std::path::Path\", to_owned)]",
+            "#[enum_from_variant(\"u32\", cast)]",
+            "#[enum_from_variant(\"Response\", via = \"Self::V0 { status: err.status }\")]",
+        ];
+        const ENUM_ATTRIBUTES: &[&str] = &[
+            "",
+            "#[non_exhaustive]",
+            "#[enum_from_variant(param = \"source\")]",
+            "#[enum_from(V0 <- DbError)]",
+            "#[enum_from(Missing <- DbError)]",
+            "#[enum_from_variant(method_style = \"lowercase\")]",
+            "#[enum_from_variant(method_style = \"shouting\")]",
+            "#[enum_from_variant(strict)]",
+            "#[enum_from_variant(vis = \"pub(crate)\")]",
+            "#[enum_from_variant(bound = \"Self: Send\")]",
+            "#[enum_from_variant(crate = \"::reexport\")]",
+            "#[enum_from_variant(bidirectional(Other { V0 <-> A, V1 <-> B }))]",
+            "#[enum_from_variant(transparent, deref)]",
+            "#[enum_from_variant(display_delegate)]",
+            "#[enum_from_variant(doc_links)]",
+            "#[enum_from_variant(assert_impls)]",
+            "#[enum_from_variant(validate_only)]",
+            "#[enum_from_variant(flatten(V0) { \"DbError\" => Database })]",
+            "#[enum_from_variant(group(\"SubErr\") { A => V0, B => V1 })]",
+            "#[enum_from_variant(module = \"conversions\")]",
+            "#[enum_from_variant(table((\"DbError\", V0), (\"u8\", V1)))]",
+            "#[enum_from_variant(box_all(\"DbError\", \"PoolError\") => V0)]",
+        ];
+        const GENERICS: &[&str] = &["", "<T>", "<'a, T: Clone>"];
+
+        let mut rng = Lcg(0x5eed);
+        for _ in 0..500 {
+            let mut source = format!("{}\nenum E{} {{\n", rng.pick(ENUM_ATTRIBUTES), rng.pick(GENERICS));
+            let variant_count: usize = rng.pick(&["1", "2", "3", "4"]).parse().unwrap();
+            for i in 0..variant_count {
+                let (first, second) = (rng.pick(ATTRIBUTES), rng.pick(ATTRIBUTES));
+                source += &format!("{}\n{}\nV{}{},\n", first, second, i, rng.pick(FIELDS));
+            }
+            source += "}";
+            let input: DeriveInput = syn::parse_str(&source).unwrap_or_else(|err| panic!("{}: {}", err, source));
+            expand(input);
+        }
+    }
+
+    #[test]
+    fn expand_rejects_non_enums() {
+        let expanded = expand(parse_quote!(
+            struct MainError;
+        ));
+        assert!(expanded
+            .to_string()
+            .contains("EnumFromVariant can only be derived for enums"));
+    }
+
+    #[test]
+    fn to_snake_case_splits_words() {
+        assert_eq!(to_snake_case("DbError"), "db_error");
+        assert_eq!(to_snake_case("HTTPError"), "http_error");
+        assert_eq!(to_snake_case("Utf8Error"), "utf8_error");
+        assert_eq!(to_snake_case("Io"), "io");
+    }
+}
@@ -0,0 +1,76 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("DbError", accessors)]
+    Database(DbError),
+    #[enum_from_variant("NetworkError", "std::fmt::Error", accessors)]
+    Network(String),
+}
+
+#[test]
+fn is_accessor_matches_its_variant() {
+    assert!(MainError::from(DbError).is_database());
+    assert!(!MainError::from(DbError).is_network());
+    assert!(MainError::from(NetworkError).is_network());
+}
+
+#[test]
+fn as_accessor_borrows_the_field() {
+    assert_eq!(MainError::from(DbError).as_database(), Some(&DbError));
+    assert_eq!(MainError::from(DbError).as_network(), None);
+    assert_eq!(
+        MainError::from(NetworkError).as_network().map(String::as_str),
+        Some("network")
+    );
+}
+
+mod scoped {
+    use super::DbError;
+    use enum_from_variant::EnumFromVariant;
+
+    #[derive(Debug, EnumFromVariant)]
+    #[enum_from_variant(vis = "pub(crate)")]
+    pub enum ScopedError {
+        #[enum_from_variant("DbError", accessors)]
+        Database(DbError),
+    }
+}
+
+#[test]
+fn accessors_follow_requested_visibility() {
+    assert!(scoped::ScopedError::from(DbError).is_database());
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum StoreError {
+    #[enum_from_variant("DbError", into_method)]
+    Database(DbError),
+    #[enum_from_variant("NetworkError", "std::fmt::Error", into_method)]
+    Network(String),
+}
+
+#[test]
+fn into_method_takes_the_value_of_its_variant() {
+    assert_eq!(StoreError::from(DbError).into_database().unwrap(), DbError);
+    assert_eq!(StoreError::from(NetworkError).into_network().unwrap(), "network");
+}
+
+#[test]
+fn into_method_gives_back_other_variants() {
+    let err = StoreError::from(NetworkError).into_database().unwrap_err();
+    assert!(matches!(err, StoreError::Network(msg) if msg == "network"));
+}
@@ -0,0 +1,41 @@
+// Builds in both configurations: the generated code names `std` by default, and `alloc` with
+// `cargo test --no-default-features --features alloc --test alloc`. The test crate is then `no_std`, so a `std` path
+// left in the generated code fails to resolve.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, PartialEq)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum AllocError {
+    #[enum_from_variant("u16")]
+    Code(String),
+    #[enum_from_variant("DbError", boxed)]
+    Database(Box<DbError>),
+    #[enum_from_variant("u8", collect)]
+    Codes(Vec<u8>),
+    #[enum_from_variant("&'static str")]
+    Message(Cow<'static, str>),
+    #[enum_from_variant("char", prefix = "char: ")]
+    Char(String),
+}
+
+#[test]
+fn allocating_fields_are_built() {
+    assert!(matches!(AllocError::from(404u16), AllocError::Code(code) if code == "404"));
+    assert!(matches!(AllocError::from(DbError), AllocError::Database(db) if *db == DbError));
+    assert!(matches!(AllocError::from(1u8), AllocError::Codes(codes) if codes == [1]));
+    assert!(matches!(
+        AllocError::from("borrowed"),
+        AllocError::Message(Cow::Borrowed("borrowed"))
+    ));
+    assert!(matches!(AllocError::from('x'), AllocError::Char(msg) if msg == "char: x"));
+}
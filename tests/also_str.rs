@@ -0,0 +1,15 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("String", also_str)]
+    Message(String),
+}
+
+#[test]
+fn also_str_generates_string_and_str_conversions() {
+    let owned: MainError = String::from("owned").into();
+    let borrowed: MainError = "borrowed".into();
+    assert!(matches!(owned, MainError::Message(msg) if msg == "owned"));
+    assert!(matches!(borrowed, MainError::Message(msg) if msg == "borrowed"));
+}
@@ -0,0 +1,32 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct DbError;
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "db")
+    }
+}
+
+impl std::error::Error for DbError {}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum AppError {
+    #[enum_from_variant("DbError", anyhow)]
+    Other(anyhow::Error),
+}
+
+fn query() -> Result<(), AppError> {
+    Err(DbError)?
+}
+
+#[test]
+fn anyhow_wraps_the_source_in_the_error() {
+    let Err(AppError::Other(err)) = query() else {
+        panic!("expected an error");
+    };
+    assert_eq!(err.to_string(), "db");
+    assert!(err.downcast_ref::<DbError>().is_some());
+}
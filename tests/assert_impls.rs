@@ -0,0 +1,60 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(assert_impls)]
+pub enum MainError<'a, T> {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    #[enum_from_variant("&'a str")]
+    Message(&'a str),
+    #[enum_from_variant("Vec<T>")]
+    Values(Vec<T>),
+    #[enum_from_variant("u64", try)]
+    Small(u8),
+    #[enum_from_variant("Wrapper<U>", via = "err.0.into()", where = "U: Into<u32>")]
+    Code(u32),
+}
+
+pub struct Wrapper<U>(pub U);
+
+#[test]
+fn checked_conversions_still_convert() {
+    assert!(matches!(MainError::<()>::from(DbError), MainError::Database(DbError)));
+    assert!(matches!(MainError::<()>::from("db"), MainError::Message("db")));
+    assert!(matches!(MainError::<u8>::from(vec![1]), MainError::Values(values) if values == [1]));
+    assert!(matches!(MainError::<()>::try_from(3u64), Ok(MainError::Small(3))));
+    assert!(matches!(MainError::<()>::from(Wrapper(7u8)), MainError::Code(7)));
+}
+
+#[derive(Debug)]
+pub struct PoolError;
+
+// The enum's bounds may name `Self`, which the free check function spells out as the enum.
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(assert_impls, bound = "Self: Send")]
+pub enum SendError<T> {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    #[enum_from_variant("PoolError", manual)]
+    Pool(PoolError),
+    Value(T),
+}
+
+impl<T> From<PoolError> for SendError<T>
+where
+    Self: Send,
+{
+    fn from(err: PoolError) -> Self {
+        SendError::Pool(err)
+    }
+}
+
+#[test]
+fn checked_conversions_may_be_bounded_on_self() {
+    assert!(matches!(SendError::<u8>::from(DbError), SendError::Database(DbError)));
+    assert!(matches!(SendError::<u8>::from(PoolError), SendError::Pool(PoolError)));
+    assert!(matches!(SendError::Value(1u8), SendError::Value(1)));
+}
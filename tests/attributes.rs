@@ -0,0 +1,294 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+/// Errors returned by the service.
+#[derive(Debug, EnumFromVariant)]
+pub enum DocumentedError {
+    /// The database rejected the query.
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    #[enum_from_variant("NetworkError")]
+    /// The request never reached the server.
+    Network(String),
+}
+
+#[test]
+fn doc_comments_around_attribute_are_ignored() {
+    assert!(matches!(
+        DocumentedError::from(DbError),
+        DocumentedError::Database(DbError)
+    ));
+    assert!(matches!(DocumentedError::from(NetworkError), DocumentedError::Network(msg) if msg == "network"));
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[non_exhaustive]
+pub enum NonExhaustiveError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    #[non_exhaustive]
+    #[enum_from_variant("NetworkError")]
+    Network(String),
+}
+
+#[test]
+fn non_exhaustive_enum_and_variant_are_supported() {
+    assert!(matches!(
+        NonExhaustiveError::from(DbError),
+        NonExhaustiveError::Database(DbError)
+    ));
+    assert!(matches!(NonExhaustiveError::from(NetworkError), NonExhaustiveError::Network(msg) if msg == "network"));
+}
+
+// Derive input is already cfg-expanded, so only the active `cfg_attr` reaches the macro.
+#[derive(Debug, EnumFromVariant)]
+pub enum CfgAttrError {
+    #[cfg_attr(test, enum_from_variant("DbError"))]
+    Database(DbError),
+    #[cfg_attr(not(test), enum_from_variant("DbError"))]
+    #[cfg_attr(test, doc = "Only converted from `NetworkError` when testing.")]
+    #[cfg_attr(test, enum_from_variant("NetworkError"))]
+    Network(String),
+}
+
+#[test]
+fn cfg_attr_gated_attributes_follow_cfg() {
+    assert!(matches!(CfgAttrError::from(DbError), CfgAttrError::Database(DbError)));
+    assert!(matches!(CfgAttrError::from(NetworkError), CfgAttrError::Network(msg) if msg == "network"));
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Context;
+
+#[derive(Debug, EnumFromVariant, serde::Serialize)]
+pub enum SerializedError {
+    #[serde(rename = "db")]
+    #[enum_from_variant("DbError")]
+    Database(#[serde(skip)] DbError),
+    Io {
+        #[serde(skip)]
+        #[from]
+        source: NetworkError,
+        #[serde(skip)]
+        #[enum_from_variant(default)]
+        context: Context,
+    },
+}
+
+#[test]
+fn unrelated_field_attributes_are_ignored() {
+    assert!(matches!(
+        SerializedError::from(DbError),
+        SerializedError::Database(DbError)
+    ));
+    assert!(matches!(
+        SerializedError::from(NetworkError),
+        SerializedError::Io { context: Context, .. }
+    ));
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct QuotaError {
+    pub limit: u32,
+}
+
+// Enum-level `serde` attributes sit on either side of the enum's own `#[enum_from_variant(..)]`.
+#[derive(Debug, EnumFromVariant, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[enum_from_variant(table(("QuotaError", QuotaExceeded)))]
+#[serde(tag = "kind")]
+pub enum ApiError {
+    #[enum_from_variant("u16")]
+    #[serde(rename = "status")]
+    HttpStatus {
+        source: u16,
+    },
+    QuotaExceeded(QuotaError),
+    #[serde(alias = "timeout")]
+    #[enum_from_variant("std::time::Duration", via = "err.as_secs()")]
+    TimedOut {
+        source: u64,
+    },
+}
+
+#[test]
+fn serde_attributes_are_ignored() {
+    assert!(matches!(ApiError::from(404u16), ApiError::HttpStatus { source: 404 }));
+    assert!(matches!(
+        ApiError::from(QuotaError { limit: 10 }),
+        ApiError::QuotaExceeded(QuotaError { limit: 10 })
+    ));
+    assert!(matches!(
+        ApiError::from(std::time::Duration::from_secs(30)),
+        ApiError::TimedOut { source: 30 }
+    ));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum DuplicateSourceError {
+    #[enum_from_variant("NetworkError", "NetworkError")]
+    Network(String),
+}
+
+#[test]
+fn duplicate_source_generates_one_impl() {
+    assert!(matches!(DuplicateSourceError::from(NetworkError), DuplicateSourceError::Network(msg) if msg == "network"));
+}
+
+// A copy-pasted attribute repeats `NetworkError`, while its other sources and options still apply.
+#[derive(Debug, EnumFromVariant)]
+pub enum RepeatedAttributeError {
+    #[enum_from_variant("NetworkError")]
+    #[enum_from_variant("NetworkError", "u16", accessors)]
+    Network(String),
+}
+
+#[test]
+fn sources_are_read_from_every_attribute() {
+    assert!(
+        matches!(RepeatedAttributeError::from(NetworkError), RepeatedAttributeError::Network(msg) if msg == "network")
+    );
+    assert!(RepeatedAttributeError::from(404u16).is_network());
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum r#Match {
+    #[enum_from_variant("DbError", accessors)]
+    r#Type(DbError),
+    #[enum_from_variant("u32", try)]
+    Code(u8),
+}
+
+#[test]
+fn raw_identifier_names_are_unrawed() {
+    use std::convert::TryFrom;
+
+    let err = Match::from(DbError);
+    assert!(err.is_type());
+    assert!(matches!(err.as_type(), Some(DbError)));
+    let err = Match::try_from(300u32).unwrap_err();
+    assert_eq!(err.target_type, "Match::Code");
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub struct ReportError;
+
+// Field attributes of other derives, like `miette`'s `#[diagnostic_source]`, are left for them to read.
+#[derive(Debug, EnumFromVariant, serde::Serialize)]
+#[enum_from_variant(strict)]
+pub enum DiagnosticError {
+    #[enum_from_variant("ReportError", miette_source)]
+    Report(#[serde(rename = "cause")] ReportError),
+}
+
+#[test]
+fn miette_source_moves_the_source_into_an_attributed_field() {
+    assert!(matches!(
+        DiagnosticError::from(ReportError),
+        DiagnosticError::Report(ReportError)
+    ));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum HandWrittenError {
+    #[enum_from_variant("NetworkError")]
+    Network(String),
+    #[enum_from_variant("DbError", manual, accessors)]
+    Database(DbError),
+}
+
+// Written by hand, e.g. to log or count the conversion in ways the derive has no option for.
+impl From<DbError> for HandWrittenError {
+    fn from(err: DbError) -> Self {
+        HandWrittenError::Database(err)
+    }
+}
+
+#[test]
+fn manual_conversion_is_left_to_the_hand_written_impl() {
+    assert!(HandWrittenError::from(DbError).is_database());
+    assert!(matches!(HandWrittenError::from(NetworkError), HandWrittenError::Network(msg) if msg == "network"));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum AbsolutePathError {
+    #[enum_from_variant("::std::num::ParseIntError")]
+    Parse(std::num::ParseIntError),
+    #[enum_from_variant("::enum_from_variant::ConversionError", ext)]
+    Conversion(enum_from_variant::ConversionError),
+}
+
+#[test]
+fn leading_colon_paths_are_absolute_sources() {
+    use AbsolutePathErrorFromExt as _;
+
+    let err = "x".parse::<u8>().unwrap_err();
+    assert!(matches!(AbsolutePathError::from(err), AbsolutePathError::Parse(_)));
+    let err = enum_from_variant::ConversionError {
+        source_type: "u64",
+        target_type: "MainError::Small",
+    };
+    assert!(matches!(
+        AbsolutePathError::from_conversion_error(err),
+        AbsolutePathError::Conversion(_)
+    ));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum NestedGenericError {
+    #[enum_from_variant("std::collections::HashMap<String, Vec<u8>>")]
+    Blobs(std::collections::HashMap<String, Vec<u8>>),
+    #[enum_from_variant(
+        "std::collections::BTreeMap<u8, Vec<Option<std::borrow::Cow<'static, str>>>>",
+        via = "err.len()"
+    )]
+    Entries(usize),
+}
+
+#[test]
+fn nested_generic_paths_are_sources() {
+    let blobs = std::collections::HashMap::from([("key".to_string(), vec![1u8, 2])]);
+    assert!(matches!(NestedGenericError::from(blobs), NestedGenericError::Blobs(blobs) if blobs["key"] == [1, 2]));
+    let entries = std::collections::BTreeMap::from([(1u8, vec![Some("a".into()), None])]);
+    assert!(matches!(
+        NestedGenericError::from(entries),
+        NestedGenericError::Entries(1)
+    ));
+}
+
+// Kept from `rustfmt`, which would normalize the spacing.
+#[rustfmt::skip]
+#[derive(Debug, EnumFromVariant)]
+#[ enum_from_variant ( method_prefix = "new_" ) ]
+pub enum SpacedError {
+    # [ enum_from_variant ( " NetworkError " , accessors ) ]
+    Network(String),
+    #[enum_from_variant(
+        "DbError",
+        ext,
+    )]
+    Database(DbError),
+}
+
+#[test]
+fn attribute_spacing_does_not_matter() {
+    use SpacedErrorFromExt as _;
+
+    assert!(SpacedError::from(NetworkError).is_network());
+    assert!(matches!(
+        SpacedError::new_db_error(DbError),
+        SpacedError::Database(DbError)
+    ));
+}
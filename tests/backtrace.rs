@@ -0,0 +1,22 @@
+use enum_from_variant::EnumFromVariant;
+use std::backtrace::{Backtrace, BacktraceStatus};
+
+#[derive(Debug)]
+pub struct IoError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("IoError", backtrace)]
+    Io { source: IoError, backtrace: Backtrace },
+}
+
+#[test]
+fn backtrace_is_captured_on_conversion() {
+    // Capturing is off unless asked for; the setting is read on the first capture.
+    std::env::set_var("RUST_LIB_BACKTRACE", "1");
+    let MainError::Io {
+        source: IoError,
+        backtrace,
+    } = MainError::from(IoError);
+    assert_eq!(backtrace.status(), BacktraceStatus::Captured);
+}
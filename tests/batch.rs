@@ -0,0 +1,46 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+#[derive(Debug)]
+pub struct DatabaseError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from(Network <- NetworkError, Database <- DatabaseError)]
+pub enum MainError {
+    Network(String),
+    Database(DatabaseError),
+}
+
+#[test]
+fn batch_attribute_generates_conversions() {
+    assert!(matches!(MainError::from(NetworkError), MainError::Network(msg) if msg == "network"));
+    assert!(matches!(
+        MainError::from(DatabaseError),
+        MainError::Database(DatabaseError)
+    ));
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(table(("NetworkError", Network), ("DatabaseError", Database)))]
+pub enum TableError {
+    Network(String),
+    Database(DatabaseError),
+}
+
+#[test]
+fn table_attribute_generates_conversions() {
+    assert!(matches!(TableError::from(NetworkError), TableError::Network(msg) if msg == "network"));
+    assert!(matches!(
+        TableError::from(DatabaseError),
+        TableError::Database(DatabaseError)
+    ));
+}
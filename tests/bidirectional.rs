@@ -0,0 +1,37 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, PartialEq)]
+pub enum WireError {
+    NetErr(String),
+    Timeout { after_ms: u64 },
+    Closed,
+}
+
+#[derive(Debug, PartialEq, EnumFromVariant)]
+#[enum_from_variant(bidirectional(WireError { Network <-> NetErr, Timeout <-> Timeout, Closed <-> Closed }))]
+pub enum AppError {
+    Network(String),
+    Timeout { after_ms: u64 },
+    Closed,
+}
+
+#[test]
+fn converts_into_the_other_enum() {
+    assert_eq!(
+        WireError::from(AppError::Network("reset".to_string())),
+        WireError::NetErr("reset".to_string())
+    );
+    assert_eq!(
+        WireError::from(AppError::Timeout { after_ms: 30 }),
+        WireError::Timeout { after_ms: 30 }
+    );
+}
+
+#[test]
+fn converts_from_the_other_enum() {
+    assert_eq!(AppError::from(WireError::Closed), AppError::Closed);
+    assert_eq!(
+        AppError::from(WireError::NetErr("reset".to_string())),
+        AppError::Network("reset".to_string())
+    );
+}
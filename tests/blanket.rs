@@ -0,0 +1,35 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, PartialEq)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant(blanket)]
+    Message(String),
+}
+
+// `DbError` is local and doesn't implement `Into<String>`, so rustc accepts both impls.
+#[derive(Debug, EnumFromVariant)]
+pub enum StoreError {
+    #[enum_from_variant(blanket)]
+    Message(String),
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+}
+
+#[test]
+fn blanket_accepts_anything_into_the_field() {
+    let from_str: MainError = "timeout".into();
+    let from_string: MainError = String::from("refused").into();
+    let from_char: MainError = 'x'.into();
+    assert!(matches!(from_str, MainError::Message(msg) if msg == "timeout"));
+    assert!(matches!(from_string, MainError::Message(msg) if msg == "refused"));
+    assert!(matches!(from_char, MainError::Message(msg) if msg == "x"));
+}
+
+#[test]
+fn blanket_sits_next_to_local_sources() {
+    assert!(matches!(StoreError::from("timeout"), StoreError::Message(msg) if msg == "timeout"));
+    assert!(matches!(StoreError::from(DbError), StoreError::Database(DbError)));
+}
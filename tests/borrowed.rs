@@ -0,0 +1,58 @@
+// Every way a borrowed source can fill an owned field, side by side.
+use enum_from_variant::EnumFromVariant;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Code(u16);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub name: String,
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BorrowedError {
+    #[enum_from_variant("&Code", via = "*err")]
+    Code(Code),
+    #[enum_from_variant("&Config", to_owned)]
+    Config(Config),
+    #[enum_from_variant("&Vec<u8>", via = "err.clone()")]
+    Bytes(Vec<u8>),
+    #[enum_from_variant("&'static str")]
+    Message(String),
+    #[enum_from_variant("&'static [u8]", via = "err.len()", via_ref)]
+    Length(usize),
+    #[enum_from_variant("&'static std::path::Path")]
+    Path(Cow<'static, std::path::Path>),
+}
+
+#[test]
+fn copy_sources_are_dereferenced() {
+    let code = Code(404);
+    assert!(matches!(BorrowedError::from(&code), BorrowedError::Code(Code(404))));
+}
+
+#[test]
+fn clone_sources_are_cloned() {
+    let config = Config { name: "db".to_string() };
+    assert!(matches!(BorrowedError::from(&config), BorrowedError::Config(cloned) if cloned == config));
+    let bytes = vec![1, 2, 3];
+    assert!(matches!(BorrowedError::from(&bytes), BorrowedError::Bytes(cloned) if cloned == bytes));
+}
+
+#[test]
+fn str_sources_are_stringified() {
+    assert!(matches!(BorrowedError::from("timeout"), BorrowedError::Message(msg) if msg == "timeout"));
+}
+
+#[test]
+fn via_ref_borrows_the_source() {
+    let bytes: &'static [u8] = b"four";
+    assert!(matches!(BorrowedError::from(&bytes), BorrowedError::Length(4)));
+}
+
+#[test]
+fn cow_fields_keep_the_borrow() {
+    let path = std::path::Path::new("/tmp");
+    assert!(matches!(BorrowedError::from(path), BorrowedError::Path(Cow::Borrowed(p)) if p == path));
+}
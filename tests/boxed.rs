@@ -0,0 +1,157 @@
+use enum_from_variant::EnumFromVariant;
+use std::error::Error;
+use std::fmt;
+
+type BoxError = Box<dyn Error>;
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parse failed")
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("ParseError", boxed)]
+    Parse(BoxError),
+}
+
+#[test]
+fn boxed_flag_boxes_through_type_alias() {
+    let err: MainError = ParseError.into();
+    let MainError::Parse(inner) = err;
+    assert_eq!(inner.to_string(), "parse failed");
+    assert!(inner.downcast_ref::<ParseError>().is_some());
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ThreadSafeError {
+    #[enum_from_variant("ParseError", boxed_dyn)]
+    Dyn(Box<dyn Error + Send + Sync + 'static>),
+}
+
+#[test]
+fn boxed_dyn_boxes_send_sync_source() {
+    let err: ThreadSafeError = ParseError.into();
+    let ThreadSafeError::Dyn(inner) = err;
+    std::thread::spawn(move || assert!(inner.downcast_ref::<ParseError>().is_some()))
+        .join()
+        .unwrap();
+}
+
+#[derive(Debug)]
+pub struct IoFailure;
+
+impl fmt::Display for IoFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "io failed")
+    }
+}
+
+impl Error for IoFailure {}
+
+#[derive(Debug)]
+pub struct Timeout;
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out")
+    }
+}
+
+impl Error for Timeout {}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(box_all("ParseError", "IoFailure", "Timeout") => Other)]
+pub enum AppError {
+    Other(BoxError),
+}
+
+#[test]
+fn box_all_boxes_every_source_into_one_variant() {
+    let AppError::Other(inner) = ParseError.into();
+    assert!(inner.downcast_ref::<ParseError>().is_some());
+    let AppError::Other(inner) = IoFailure.into();
+    assert!(inner.downcast_ref::<IoFailure>().is_some());
+    let AppError::Other(inner) = Timeout.into();
+    assert_eq!(inner.to_string(), "timed out");
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum CauseError {
+    #[enum_from_variant("ParseError")]
+    Cause(Option<Box<ParseError>>),
+    #[enum_from_variant("Timeout")]
+    Dyn(Option<std::boxed::Box<dyn Error>>),
+}
+
+#[test]
+fn option_box_field_wraps_source_twice() {
+    assert!(matches!(CauseError::from(ParseError), CauseError::Cause(Some(_))));
+    let CauseError::Dyn(Some(inner)) = CauseError::from(Timeout) else {
+        panic!("expected `CauseError::Dyn(Some(_))`");
+    };
+    assert!(inner.downcast_ref::<Timeout>().is_some());
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum EitherFormError {
+    #[enum_from_variant("ParseError", also_boxed)]
+    Parse(ParseError),
+}
+
+#[test]
+fn also_boxed_accepts_the_source_and_its_box() {
+    assert!(matches!(
+        EitherFormError::from(ParseError),
+        EitherFormError::Parse(ParseError)
+    ));
+    assert!(matches!(
+        EitherFormError::from(Box::new(ParseError)),
+        EitherFormError::Parse(ParseError)
+    ));
+}
+
+pub trait Handler: std::fmt::Debug {
+    fn handle(&self) -> u8;
+}
+
+#[derive(Debug)]
+pub struct Retry;
+
+impl Handler for Retry {
+    fn handle(&self) -> u8 {
+        3
+    }
+}
+
+// A source of the field's own type is moved, rather than boxed again or stringified.
+#[derive(Debug, EnumFromVariant)]
+pub enum HandlerError {
+    #[enum_from_variant("Box<dyn Handler>")]
+    Handler(Box<dyn Handler>),
+    #[enum_from_variant("Option<Box<dyn Handler + Send>>")]
+    Maybe(Option<Box<dyn Handler + Send>>),
+    #[enum_from_variant("std::sync::Arc<dyn Handler + Send + Sync>")]
+    Shared(std::sync::Arc<dyn Handler + Send + Sync>),
+}
+
+#[test]
+fn trait_object_sources_are_moved() {
+    let handler: Box<dyn Handler> = Box::new(Retry);
+    let address = &*handler as *const dyn Handler as *const ();
+    let HandlerError::Handler(moved) = HandlerError::from(handler) else {
+        panic!("expected `HandlerError::Handler`");
+    };
+    assert_eq!(&*moved as *const dyn Handler as *const (), address);
+    assert_eq!(moved.handle(), 3);
+    let maybe: Option<Box<dyn Handler + Send>> = Some(Box::new(Retry));
+    assert!(matches!(HandlerError::from(maybe), HandlerError::Maybe(Some(handler)) if handler.handle() == 3));
+    let shared: std::sync::Arc<dyn Handler + Send + Sync> = std::sync::Arc::new(Retry);
+    assert!(matches!(HandlerError::from(shared), HandlerError::Shared(handler) if handler.handle() == 3));
+}
@@ -0,0 +1,49 @@
+use enum_from_variant::EnumFromVariant;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct IoFailure;
+
+impl fmt::Display for IoFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "disk unplugged")
+    }
+}
+
+impl Error for IoFailure {}
+
+#[derive(Debug)]
+pub struct ThirdPartyError {
+    cause: IoFailure,
+}
+
+impl fmt::Display for ThirdPartyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "third party failed")
+    }
+}
+
+impl Error for ThirdPartyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.cause)
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("ThirdPartyError", chain)]
+    External {
+        message: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+#[test]
+fn chain_fills_message_and_source() {
+    let err: MainError = ThirdPartyError { cause: IoFailure }.into();
+    let MainError::External { message, source } = err;
+    assert_eq!(message, "third party failed");
+    assert!(source.downcast_ref::<ThirdPartyError>().is_some());
+    assert_eq!(source.source().unwrap().to_string(), "disk unplugged");
+}
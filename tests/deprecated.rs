@@ -0,0 +1,21 @@
+#![deny(deprecated)]
+
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct LegacyError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[deprecated(note = "use `MainError::Other` instead")]
+    #[enum_from_variant("LegacyError")]
+    Legacy(LegacyError),
+    Other,
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_variant_conversion_does_not_warn() {
+    let err: MainError = LegacyError.into();
+    assert!(matches!(err, MainError::Legacy(_)));
+}
@@ -0,0 +1,40 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct DbError;
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "database unavailable")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(display_delegate)]
+pub enum MainError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    #[enum_from_variant("&str")]
+    Message(String, ()),
+    Http {
+        status: u16,
+        body: String,
+    },
+    Timeout,
+}
+
+#[test]
+fn display_delegates_to_each_variant() {
+    assert_eq!(MainError::from(DbError).to_string(), "database unavailable");
+    assert_eq!(MainError::from("bad input").to_string(), "bad input");
+    assert_eq!(
+        MainError::Http {
+            status: 503,
+            body: String::new()
+        }
+        .to_string(),
+        "503"
+    );
+    assert_eq!(MainError::Timeout.to_string(), "Timeout");
+}
@@ -0,0 +1,21 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(emit_count)]
+pub enum MainError {
+    #[enum_from_variant("DbError", also_boxed)]
+    Database(DbError),
+    #[enum_from_variant("u8")]
+    Pool(u8),
+    #[enum_from_variant("u64", try)]
+    Small(u8),
+    Unit,
+}
+
+#[test]
+fn emit_count_counts_generated_impls() {
+    assert_eq!(MainError::GENERATED_FROM_COUNT, 4);
+}
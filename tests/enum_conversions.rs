@@ -0,0 +1,38 @@
+use enum_from_variant::enum_conversions;
+
+#[derive(Debug, PartialEq)]
+pub struct NetworkError;
+
+#[derive(Debug, PartialEq)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub enum ClientError {
+    Network(NetworkError),
+    Database(Box<DbError>),
+}
+
+pub mod server {
+    #[derive(Debug)]
+    pub enum ServerError {
+        Net(super::NetworkError),
+    }
+}
+
+enum_conversions! {
+    NetworkError => (ClientError::Network, server::ServerError::Net),
+    DbError => (ClientError::Database),
+}
+
+#[test]
+fn one_source_fans_out_to_several_enums() {
+    assert!(matches!(
+        ClientError::from(NetworkError),
+        ClientError::Network(NetworkError)
+    ));
+    assert!(matches!(
+        server::ServerError::from(NetworkError),
+        server::ServerError::Net(NetworkError)
+    ));
+    assert!(matches!(ClientError::from(DbError), ClientError::Database(err) if *err == DbError));
+}
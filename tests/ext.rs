@@ -0,0 +1,78 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct HTTPError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("DbError", ext)]
+    Database(DbError),
+    #[enum_from_variant("HTTPError", ext)]
+    Http(HTTPError),
+}
+
+#[test]
+fn ext_trait_methods_construct_variants() {
+    assert!(matches!(
+        MainError::from_db_error(DbError),
+        MainError::Database(DbError)
+    ));
+    assert!(matches!(
+        MainError::from_http_error(HTTPError),
+        MainError::Http(HTTPError)
+    ));
+}
+
+#[test]
+fn ext_trait_is_nameable() {
+    fn convert<E: MainErrorFromExt>(err: DbError) -> E {
+        E::from_db_error(err)
+    }
+    assert!(matches!(convert::<MainError>(DbError), MainError::Database(DbError)));
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(method_style = "lowercase", method_prefix = "wrap_")]
+pub enum StyledError {
+    #[enum_from_variant("DbError", ext)]
+    Database(DbError),
+}
+
+#[test]
+fn ext_method_names_follow_enum_style() {
+    assert!(matches!(
+        StyledError::wrap_dberror(DbError),
+        StyledError::Database(DbError)
+    ));
+}
+
+pub trait Backend {
+    type Error;
+}
+
+pub struct Memory;
+
+#[derive(Debug, PartialEq)]
+pub struct MemoryError;
+
+impl Backend for Memory {
+    type Error = MemoryError;
+}
+
+// `From<T::Error>` could overlap with `From<T> for T`, but a method can't.
+#[derive(Debug, EnumFromVariant)]
+pub enum StoreError<T: Backend> {
+    #[enum_from_variant("T::Error", ext)]
+    Backend(T::Error),
+}
+
+#[test]
+fn ext_converts_from_associated_types() {
+    assert!(matches!(
+        StoreError::<Memory>::from_error(MemoryError),
+        StoreError::Backend(MemoryError)
+    ));
+}
@@ -0,0 +1,64 @@
+// `db` is deliberately not a feature of this crate.
+#![allow(unexpected_cfgs)]
+
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("DbError", feature = "db")]
+    Database(DbError),
+    #[enum_from_variant("NetworkError")]
+    Network(String),
+}
+
+// The gated conversion is not emitted, so this impl doesn't conflict with it.
+impl From<DbError> for MainError {
+    fn from(_: DbError) -> MainError {
+        MainError::Network("db disabled".to_string())
+    }
+}
+
+#[test]
+fn feature_gated_conversion_is_not_emitted() {
+    let err: MainError = DbError.into();
+    assert!(matches!(err, MainError::Network(msg) if msg == "db disabled"));
+}
+
+#[test]
+fn ungated_conversion_is_still_emitted() {
+    let err: MainError = NetworkError.into();
+    assert!(matches!(err, MainError::Network(msg) if msg == "network"));
+}
+
+// A gated-out enum takes its derive with it, so its conversions may name types that don't exist.
+#[cfg(feature = "db")]
+#[derive(Debug, EnumFromVariant)]
+pub enum GatedError {
+    #[enum_from_variant("MissingType")]
+    Missing(MissingType),
+}
+
+#[cfg(not(feature = "db"))]
+#[cfg_attr(not(feature = "db"), derive(Debug, EnumFromVariant))]
+pub enum GatedError {
+    #[enum_from_variant("NetworkError")]
+    Network(String),
+}
+
+#[test]
+fn cfg_gated_enums_keep_their_conversions() {
+    assert!(matches!(GatedError::from(NetworkError), GatedError::Network(msg) if msg == "network"));
+}
@@ -0,0 +1,406 @@
+use enum_from_variant::EnumFromVariant;
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct SourceErr;
+
+#[derive(Debug)]
+pub struct Checked;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum TaggedError {
+    #[enum_from_variant("SourceErr")]
+    Tagged(SourceErr, PhantomData<Checked>),
+    #[enum_from_variant("Checked")]
+    Marked(Checked, (), PhantomData<SourceErr>),
+}
+
+#[test]
+fn trailing_marker_fields_are_filled() {
+    assert!(matches!(
+        TaggedError::from(SourceErr),
+        TaggedError::Tagged(SourceErr, PhantomData)
+    ));
+    assert!(matches!(
+        TaggedError::from(Checked),
+        TaggedError::Marked(Checked, (), PhantomData)
+    ));
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SubError(u8);
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BatchError {
+    #[enum_from_variant("SubError", collect)]
+    Errors(Vec<SubError>),
+}
+
+#[test]
+fn collect_wraps_source_in_vec() {
+    let BatchError::Errors(errors) = SubError(1).into();
+    assert_eq!(errors, vec![SubError(1)]);
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum RequestError {
+    #[enum_from_variant("Vec<(String, String)>", collect)]
+    Headers(std::collections::HashMap<String, String>),
+    #[enum_from_variant("Vec<SubError>", collect)]
+    Batch(std::collections::VecDeque<SubError>),
+}
+
+#[test]
+fn collect_gathers_iterable_sources() {
+    let pairs = vec![("host".to_string(), "example.com".to_string())];
+    let RequestError::Headers(headers) = pairs.into() else {
+        panic!("expected `RequestError::Headers`");
+    };
+    assert_eq!(headers["host"], "example.com");
+    let RequestError::Batch(errors) = vec![SubError(1), SubError(2)].into() else {
+        panic!("expected `RequestError::Batch`");
+    };
+    assert_eq!(errors, [SubError(1), SubError(2)]);
+}
+
+#[allow(unused_parens)]
+#[derive(Debug, EnumFromVariant)]
+pub enum GroupedError {
+    #[enum_from_variant("SourceErr")]
+    Parenthesized((SourceErr)),
+}
+
+macro_rules! wrapper_error {
+    ($name:ident, $ty:ty) => {
+        #[derive(Debug, EnumFromVariant)]
+        pub enum $name {
+            #[enum_from_variant("Checked")]
+            Wrapped($ty),
+        }
+    };
+}
+
+wrapper_error!(MacroError, Checked);
+
+macro_rules! checked {
+    () => {
+        Checked
+    };
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum TypeMacroError {
+    #[enum_from_variant("Checked")]
+    Checked(checked!()),
+}
+
+#[test]
+fn grouped_field_types_are_moved() {
+    assert!(matches!(
+        GroupedError::from(SourceErr),
+        GroupedError::Parenthesized(SourceErr)
+    ));
+    assert!(matches!(MacroError::from(Checked), MacroError::Wrapped(Checked)));
+    assert!(matches!(
+        TypeMacroError::from(Checked),
+        TypeMacroError::Checked(Checked)
+    ));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MessageError {
+    #[enum_from_variant("String", "&'static str")]
+    Msg(std::borrow::Cow<'static, str>),
+}
+
+#[test]
+fn cow_field_is_owned_from_string() {
+    let MessageError::Msg(msg) = String::from("owned").into();
+    assert!(matches!(msg, std::borrow::Cow::Owned(msg) if msg == "owned"));
+}
+
+#[test]
+fn cow_field_is_borrowed_from_static_str() {
+    let MessageError::Msg(msg) = "borrowed".into();
+    assert!(matches!(msg, std::borrow::Cow::Borrowed("borrowed")));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BorrowedMessageError<'a> {
+    #[enum_from_variant("String", "&'a str")]
+    Msg(std::borrow::Cow<'a, str>),
+}
+
+#[test]
+fn cow_field_borrows_for_the_enum_lifetime() {
+    let input = String::from("borrowed");
+    let BorrowedMessageError::Msg(msg) = input.as_str().into();
+    assert!(matches!(msg, std::borrow::Cow::Borrowed("borrowed")));
+    let BorrowedMessageError::Msg(msg) = String::from("owned").into();
+    assert!(matches!(msg, std::borrow::Cow::Owned(msg) if msg == "owned"));
+}
+
+/// Stands in for `SmallVec<[u8; 16]>`: any field type built from the source with `From` works with `into`.
+#[derive(Debug, PartialEq)]
+pub struct InlineBytes {
+    len: usize,
+    bytes: [u8; 16],
+}
+
+impl From<&[u8]> for InlineBytes {
+    fn from(slice: &[u8]) -> Self {
+        let mut bytes = [0; 16];
+        let len = slice.len().min(16);
+        bytes[..len].copy_from_slice(&slice[..len]);
+        InlineBytes { len, bytes }
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum FrameError {
+    #[enum_from_variant("&[u8]", into)]
+    Bytes(InlineBytes),
+}
+
+#[test]
+fn into_builds_arbitrary_field_types() {
+    let FrameError::Bytes(bytes) = FrameError::from(&[1u8, 2, 3][..]);
+    assert_eq!(bytes.len, 3);
+    assert_eq!(&bytes.bytes[..3], [1, 2, 3]);
+}
+
+fn answer() -> i32 {
+    42
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum CallbackError {
+    #[enum_from_variant("fn() -> i32")]
+    Callback(fn() -> i32),
+}
+
+#[test]
+fn function_pointer_is_moved() {
+    let CallbackError::Callback(callback) = CallbackError::from(answer as fn() -> i32);
+    assert_eq!(callback(), 42);
+}
+
+fn length(input: &str) -> usize {
+    input.len()
+}
+
+// The `'a` of a higher-ranked pointer is bound by the type itself, so the impl doesn't introduce it.
+#[derive(Debug, EnumFromVariant)]
+pub enum ParserError {
+    #[enum_from_variant("for<'a> fn(&'a str) -> usize")]
+    Measure(for<'a> fn(&'a str) -> usize),
+    #[enum_from_variant("fn(&str) -> &str")]
+    Trim(fn(&str) -> &str),
+}
+
+#[test]
+fn higher_ranked_function_pointer_is_moved() {
+    let ParserError::Measure(measure) = ParserError::from(length as for<'a> fn(&'a str) -> usize) else {
+        panic!("expected `ParserError::Measure`");
+    };
+    assert_eq!(measure("four"), 4);
+    let ParserError::Trim(trim) = ParserError::from(str::trim as fn(&str) -> &str) else {
+        panic!("expected `ParserError::Trim`");
+    };
+    assert_eq!(trim(" db "), "db");
+}
+
+pub static DEFAULT_SOURCE: SubError = SubError(0);
+
+#[derive(Debug, EnumFromVariant)]
+pub enum UnpathedError {
+    #[enum_from_variant("&'static SubError")]
+    Borrowed(&'static SubError),
+    #[enum_from_variant("(u8, char)")]
+    Pair((u8, char)),
+    #[enum_from_variant("*const u8")]
+    Pointer(*const u8),
+}
+
+#[test]
+fn fields_without_a_path_are_moved() {
+    let UnpathedError::Borrowed(source) = UnpathedError::from(&DEFAULT_SOURCE) else {
+        panic!("expected `UnpathedError::Borrowed`");
+    };
+    assert!(std::ptr::eq(source, &DEFAULT_SOURCE));
+    assert!(matches!(UnpathedError::from((1u8, 'a')), UnpathedError::Pair((1, 'a'))));
+    assert!(matches!(UnpathedError::from(std::ptr::null()), UnpathedError::Pointer(ptr) if ptr.is_null()));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Bounds {
+    #[enum_from_variant("(u32, u32)")]
+    Range(u32, u32),
+    #[enum_from_variant("(u8, ())")]
+    Unit(u8, ()),
+    #[enum_from_variant("(String, Vec<u8>)")]
+    Named(String, Vec<u8>),
+    #[enum_from_variant("(i8, i8, i8)")]
+    Point((i8, i8, i8), PhantomData<u8>),
+}
+
+#[test]
+fn tuple_source_is_spread_over_fields() {
+    assert!(matches!(Bounds::from((3u32, 9u32)), Bounds::Range(3, 9)));
+    assert!(matches!(Bounds::from((1u8, ())), Bounds::Unit(1, ())));
+    assert!(matches!(
+        Bounds::from((1i8, 2i8, 3i8)),
+        Bounds::Point((1, 2, 3), PhantomData)
+    ));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum TaskError {
+    #[enum_from_variant("()")]
+    Cancelled,
+}
+
+fn cancel() -> Result<u8, ()> {
+    Err(())
+}
+
+#[test]
+fn unit_converts_into_unit_variant() {
+    let run = || -> Result<u8, TaskError> { Ok(cancel()?) };
+    assert!(matches!(run(), Err(TaskError::Cancelled)));
+}
+
+mod custom {
+    #[derive(Debug, PartialEq)]
+    pub struct String(pub u8);
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum StdNamedError {
+    #[enum_from_variant("SourceErr")]
+    String(SourceErr),
+    #[enum_from_variant("u8")]
+    Box(u8),
+    #[enum_from_variant("custom::String")]
+    Custom(custom::String),
+}
+
+#[test]
+fn variants_named_after_std_types() {
+    assert!(matches!(
+        StdNamedError::from(SourceErr),
+        StdNamedError::String(SourceErr)
+    ));
+    assert!(matches!(StdNamedError::from(4u8), StdNamedError::Box(4)));
+}
+
+#[test]
+fn custom_type_named_string_is_moved_in() {
+    assert!(matches!(
+        StdNamedError::from(custom::String(1)),
+        StdNamedError::Custom(custom::String(1))
+    ));
+}
+
+#[derive(Debug)]
+pub struct Wrapper<E, Tag> {
+    pub err: E,
+    tag: PhantomData<Tag>,
+}
+
+impl<E, Tag> Wrapper<E, Tag> {
+    pub fn new(err: E) -> Self {
+        Wrapper { err, tag: PhantomData }
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum PhantomTaggedError {
+    // The tag is inferred from the field type.
+    #[enum_from_variant("SourceErr", via = "Wrapper::new(err)")]
+    Tagged(Wrapper<SourceErr, Checked>),
+}
+
+#[test]
+fn via_fills_phantom_tagged_wrapper() {
+    let PhantomTaggedError::Tagged(wrapper) = PhantomTaggedError::from(SourceErr);
+    let _: &Wrapper<SourceErr, Checked> = &wrapper;
+    assert!(matches!(wrapper.err, SourceErr));
+}
+
+mod aliased {
+    #[derive(Debug)]
+    pub struct MyErr;
+
+    pub type Result<T> = std::result::Result<T, MyErr>;
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum OutcomeError {
+    #[enum_from_variant("aliased::Result<u8>")]
+    Outcome(aliased::Result<u8>),
+}
+
+#[test]
+fn result_alias_field_is_moved_in() {
+    assert!(matches!(OutcomeError::from(Ok(3)), OutcomeError::Outcome(Ok(3))));
+    assert!(matches!(
+        OutcomeError::from(Err(aliased::MyErr)),
+        OutcomeError::Outcome(Err(aliased::MyErr))
+    ));
+}
+
+#[derive(Debug)]
+pub struct Local<T>(pub T);
+
+#[derive(Debug, EnumFromVariant)]
+pub enum WrappedError {
+    #[enum_from_variant("SourceErr", wrap = "Local")]
+    Wrap(Local<SourceErr>),
+    #[enum_from_variant("u8", wrap = "Local")]
+    Code(Local<u8>, PhantomData<Checked>),
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum UnwrappedError {
+    #[enum_from_variant("Local<SourceErr>", unwrap)]
+    Source(SourceErr),
+    #[enum_from_variant("Local<u8>", unwrap)]
+    Code(u8, PhantomData<Checked>),
+}
+
+#[test]
+fn unwrap_stores_the_newtype_field() {
+    assert!(matches!(
+        UnwrappedError::from(Local(SourceErr)),
+        UnwrappedError::Source(SourceErr)
+    ));
+    assert!(matches!(
+        UnwrappedError::from(Local(4u8)),
+        UnwrappedError::Code(4, PhantomData)
+    ));
+}
+
+#[test]
+fn wrap_builds_the_generic_newtype() {
+    assert!(matches!(
+        WrappedError::from(SourceErr),
+        WrappedError::Wrap(Local(SourceErr))
+    ));
+    assert!(matches!(
+        WrappedError::from(4u8),
+        WrappedError::Code(Local(4), PhantomData)
+    ));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum DigestError {
+    #[enum_from_variant("[u8; 32]")]
+    Hash([u8; 32]),
+}
+
+#[test]
+fn array_field_moves_the_array_in() {
+    let DigestError::Hash(hash) = DigestError::from([7u8; 32]);
+    assert_eq!(hash, [7u8; 32]);
+}
@@ -0,0 +1,34 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct PoolError(u32);
+
+#[derive(Debug)]
+pub enum StorageError {
+    Database(DbError),
+    Pool(PoolError),
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(flatten(Storage) { "DbError" => Database, "PoolError" => Pool })]
+pub enum AppError {
+    Storage(StorageError),
+    #[enum_from_variant("String")]
+    Other(String),
+}
+
+#[test]
+fn flatten_wraps_payloads_in_the_nested_variant() {
+    assert!(matches!(
+        AppError::from(DbError),
+        AppError::Storage(StorageError::Database(DbError))
+    ));
+    assert!(matches!(
+        AppError::from(PoolError(3)),
+        AppError::Storage(StorageError::Pool(PoolError(3)))
+    ));
+    assert!(matches!(AppError::from("other".to_string()), AppError::Other(msg) if msg == "other"));
+}
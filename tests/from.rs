@@ -0,0 +1,89 @@
+use enum_from_variant::EnumFromVariant;
+use std::borrow::Cow;
+use std::io;
+
+#[derive(Debug, PartialEq)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant(from)]
+    Database(DbError),
+    #[enum_from_variant(from)]
+    Io(io::Error),
+    #[enum_from_variant(from)]
+    Message(Cow<'static, str>),
+}
+
+#[test]
+fn from_infers_the_source_from_the_field() {
+    assert!(matches!(MainError::from(DbError), MainError::Database(DbError)));
+    let err: MainError = io::Error::other("disk").into();
+    assert!(matches!(err, MainError::Io(err) if err.to_string() == "disk"));
+}
+
+#[test]
+fn from_moves_the_source_in_unchanged() {
+    let err: MainError = Cow::Borrowed("static").into();
+    assert!(matches!(err, MainError::Message(Cow::Borrowed("static"))));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum FileError {
+    Io {
+        #[from]
+        source: io::Error,
+        #[enum_from_variant(default)]
+        path: String,
+        #[enum_from_variant(default)]
+        attempts: u32,
+    },
+}
+
+#[test]
+fn from_field_of_struct_variant_receives_the_source() {
+    let err: FileError = io::Error::other("disk").into();
+    let FileError::Io { source, path, attempts } = err;
+    assert_eq!(source.to_string(), "disk");
+    assert_eq!(path, "");
+    assert_eq!(attempts, 0);
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ConvertError {
+    Database(#[convert] DbError),
+    Io {
+        #[convert]
+        source: io::Error,
+        #[enum_from_variant(default)]
+        retries: u8,
+    },
+}
+
+#[test]
+fn convert_marked_field_is_the_source() {
+    assert!(matches!(ConvertError::from(DbError), ConvertError::Database(DbError)));
+    let err: ConvertError = io::Error::other("disk").into();
+    assert!(matches!(err, ConvertError::Io { retries: 0, .. }));
+}
+
+#[derive(Debug)]
+pub struct PoolError(u8);
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ReroutedError {
+    Pool(PoolError),
+    // Pool errors are reported as retryable, without listing `PoolError` again.
+    #[enum_from_variant(from_variant = "Pool")]
+    Retryable(PoolError),
+}
+
+#[test]
+fn from_variant_converts_from_a_sibling_payload() {
+    assert!(matches!(
+        ReroutedError::from(PoolError(2)),
+        ReroutedError::Retryable(PoolError(2))
+    ));
+    // The `Pool` variant itself gets no conversion.
+    assert!(!matches!(ReroutedError::from(PoolError(1)), ReroutedError::Pool(_)));
+}
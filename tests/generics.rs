@@ -0,0 +1,319 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct Ref<'a>(&'a str);
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BorrowedError<'a> {
+    #[enum_from_variant("Ref<'a>")]
+    R(Ref<'a>),
+}
+
+#[test]
+fn lifetime_from_enum_is_bound_on_source() {
+    let text = String::from("borrowed");
+    let err: BorrowedError<'_> = Ref(&text).into();
+    assert!(matches!(err, BorrowedError::R(Ref("borrowed"))));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BorrowedMessage<'a> {
+    #[enum_from_variant("&'a str")]
+    Borrowed(&'a str),
+}
+
+#[test]
+fn reference_field_is_moved_in() {
+    let text = String::from("borrowed");
+    let err: BorrowedMessage<'_> = text.as_str().into();
+    assert!(matches!(err, BorrowedMessage::Borrowed("borrowed")));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Measurement<T: From<u8>> {
+    #[enum_from_variant("u8", into_turbofish = "T")]
+    Value(T),
+}
+
+#[test]
+fn into_turbofish_names_generic_target() {
+    let Measurement::Value(value) = Measurement::<u64>::from(7u8);
+    assert_eq!(value, 7u64);
+}
+
+pub struct Pair<U, V>(pub U, pub V);
+
+impl<U, V> std::fmt::Display for Pair<U, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "pair")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum PairError {
+    #[enum_from_variant("Pair<U, V>")]
+    Message(String),
+    #[enum_from_variant("Option<(U, u8)>", via = "err.map_or(0, |(_, tag)| tag)")]
+    Tag(u8),
+}
+
+#[test]
+fn free_source_params_become_impl_params() {
+    assert!(matches!(PairError::from(Pair(1u8, "two")), PairError::Message(msg) if msg == "pair"));
+    assert!(matches!(PairError::from(Pair((), 2.0)), PairError::Message(_)));
+    assert!(matches!(PairError::from(Some(("a", 7u8))), PairError::Tag(7)));
+}
+
+pub struct Wrapper<E>(pub E);
+
+#[derive(Debug)]
+pub struct ConcreteErr;
+
+// Each instantiation gets its own non-generic impl, so the two don't overlap.
+#[derive(Debug, EnumFromVariant)]
+pub enum WrappedError {
+    #[enum_from_variant("Wrapper<ConcreteErr>", via = "err.0")]
+    Concrete(ConcreteErr),
+    #[enum_from_variant("Wrapper<u8>", via = "err.0")]
+    Code(u8),
+}
+
+#[test]
+fn concrete_instantiations_get_non_generic_impls() {
+    assert!(matches!(
+        WrappedError::from(Wrapper(ConcreteErr)),
+        WrappedError::Concrete(ConcreteErr)
+    ));
+    assert!(matches!(WrappedError::from(Wrapper(4u8)), WrappedError::Code(4)));
+}
+
+mod named {
+    use super::Wrapper;
+    use enum_from_variant::EnumFromVariant;
+
+    // A type that happens to have a one-letter name, which the field names as a type.
+    #[derive(Debug)]
+    pub struct E;
+
+    #[derive(Debug, EnumFromVariant)]
+    pub enum NamedError {
+        #[enum_from_variant("Wrapper<E>", via = "err.0")]
+        Short(E),
+    }
+
+    #[test]
+    fn names_of_field_types_are_types() {
+        assert!(matches!(NamedError::from(Wrapper(E)), NamedError::Short(E)));
+    }
+}
+
+#[derive(Debug)]
+pub struct MyError;
+
+impl std::fmt::Display for MyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "my error")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum DisplayedError {
+    #[enum_from_variant("&'a MyError")]
+    Msg(String),
+}
+
+#[test]
+fn source_lifetimes_become_impl_params() {
+    let source = MyError;
+    assert!(matches!(DisplayedError::from(&source), DisplayedError::Msg(msg) if msg == "my error"));
+}
+
+// `via` expressions can name the enum's parameters, which the impl introduces, e.g. to turbofish an inference.
+#[derive(Debug, EnumFromVariant)]
+pub enum BufferError<T: Default + Clone + std::str::FromStr> {
+    #[enum_from_variant("usize", via = "vec![T::default(); err]")]
+    Filled(Vec<T>),
+    #[enum_from_variant("&'static str", via = "err.parse::<T>().unwrap_or_default()")]
+    Parsed(T),
+    #[enum_from_variant("u8", via = "Into::<u64>::into(err) + core::mem::size_of::<T>() as u64")]
+    Sized(u64),
+}
+
+#[test]
+fn via_expressions_name_the_enum_params() {
+    assert!(matches!(BufferError::<u8>::from(3usize), BufferError::Filled(values) if values == [0, 0, 0]));
+    assert!(matches!(BufferError::<u16>::from("7"), BufferError::Parsed(7)));
+    assert!(matches!(BufferError::<u16>::from("x"), BufferError::Parsed(0)));
+    assert!(matches!(BufferError::<u32>::from(1u8), BufferError::Sized(5)));
+}
+
+// A bare `From<U>` would overlap with `From<T> for T`, so the free `U` sits inside a wrapper.
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(bound = "U: Into<T>")]
+pub enum LiftedError<T> {
+    #[enum_from_variant("Wrapper<U>", via = "err.0.into()")]
+    Value(T),
+    // Neither the impl nor the accessors introduce `U`, so the bound isn't on them.
+    #[enum_from_variant("u8", accessors)]
+    Code(u8),
+}
+
+#[test]
+fn enum_level_bounds_compose_with_free_source_params() {
+    let LiftedError::Value(value) = LiftedError::<u64>::from(Wrapper(5u8)) else {
+        panic!("expected `LiftedError::Value`");
+    };
+    assert_eq!(value, 5u64);
+    assert!(matches!(LiftedError::<String>::from(Wrapper("five")), LiftedError::Value(value) if value == "five"));
+    let code = LiftedError::<String>::from(3u8);
+    assert!(code.is_code());
+    assert_eq!(code.as_code(), Some(&3));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ReportedError {
+    #[enum_from_variant(
+        "Wrapper<E>",
+        via = "Box::new(err.0)",
+        where = "E: std::error::Error + Send + Sync + 'static"
+    )]
+    Report(Box<dyn std::error::Error + Send + Sync>),
+    #[enum_from_variant("u8")]
+    Code(u8),
+}
+
+#[test]
+fn conversion_where_clause_keeps_every_bound() {
+    let ReportedError::Report(inner) = ReportedError::from(Wrapper(std::fmt::Error)) else {
+        panic!("expected `ReportedError::Report`");
+    };
+    assert!(inner.downcast_ref::<std::fmt::Error>().is_some());
+    assert!(matches!(ReportedError::from(3u8), ReportedError::Code(3)));
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(bound = "Self: Send", bound = "T: From<u8>")]
+pub enum BoundedError<T> {
+    #[enum_from_variant("u8", into_turbofish = "T")]
+    Value(T),
+}
+
+#[test]
+fn enum_level_bounds_are_satisfied() {
+    let BoundedError::Value(value) = BoundedError::<u32>::from(3u8);
+    assert_eq!(value, 3);
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Defaulted<T = String> {
+    #[enum_from_variant("T")]
+    Value(T),
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum DefaultedConst<T: Copy = u8, const N: usize = 4> {
+    #[enum_from_variant("[T; N]", move)]
+    Values([T; N]),
+}
+
+#[test]
+fn defaulted_params_are_not_repeated_in_impls() {
+    let value: Defaulted = "a".to_string().into();
+    assert!(matches!(value, Defaulted::Value(value) if value == "a"));
+    let values: DefaultedConst = [1u8, 2, 3, 4].into();
+    assert!(matches!(values, DefaultedConst::Values([1, 2, 3, 4])));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum OverflowError<const N: usize> {
+    #[enum_from_variant("[u8; N]")]
+    Over([u8; N]),
+}
+
+#[test]
+fn const_generic_sources_flow_into_the_impl() {
+    let OverflowError::Over(bytes) = OverflowError::from([1u8, 2, 3]);
+    assert_eq!(bytes, [1, 2, 3]);
+    assert!(matches!(OverflowError::<0>::from([]), OverflowError::Over([])));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum SizedError<T>
+where
+    Self: Sized,
+    T: Clone,
+{
+    #[enum_from_variant("u8", into_turbofish = "u64", accessors)]
+    Code(u64),
+    #[enum_from_variant("String", also_str)]
+    Message(String),
+    #[enum_from_variant("Vec<T>", try)]
+    Items([T; 2]),
+}
+
+#[test]
+fn self_bounds_are_kept_on_generated_impls() {
+    assert!(SizedError::<u8>::from(4u8).is_code());
+    assert!(matches!(SizedError::<u8>::from("msg"), SizedError::Message(msg) if msg == "msg"));
+    assert!(matches!(
+        SizedError::try_from(vec![1u8, 2]),
+        Ok(SizedError::Items([1, 2]))
+    ));
+    assert!(SizedError::try_from(vec![1u8]).is_err());
+}
+
+// Each impl carries the full `<'a, T>` list, though it uses only one of them. A bare `From<T>` would overlap with
+// `From<&'a str>` at `T = &'a str`, so the type parameter sits in a `Vec`.
+#[derive(Debug, EnumFromVariant)]
+pub enum MixedError<'a, T> {
+    #[enum_from_variant("&'a str")]
+    Borrowed(&'a str),
+    #[enum_from_variant("Vec<T>")]
+    Values(Vec<T>),
+}
+
+#[test]
+fn every_impl_takes_lifetimes_and_type_params() {
+    let borrowed = String::from("borrowed");
+    assert!(matches!(
+        MixedError::<u8>::from(borrowed.as_str()),
+        MixedError::Borrowed("borrowed")
+    ));
+    assert!(matches!(MixedError::from(vec![1u8, 2]), MixedError::Values(values) if values == [1, 2]));
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DbError(u8);
+
+#[derive(Debug, PartialEq)]
+pub struct Wrapped(u8);
+
+impl From<DbError> for Wrapped {
+    fn from(err: DbError) -> Self {
+        Wrapped(err.0)
+    }
+}
+
+// The enum's own bounds, inline or in its `where` clause, carry the `Into` of the field.
+#[derive(Debug, EnumFromVariant)]
+pub enum BoundByParam<T: From<DbError>> {
+    #[enum_from_variant("DbError", into)]
+    Wrapped(T),
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BoundByWhere<T>
+where
+    T: From<DbError> + std::fmt::Debug,
+{
+    #[enum_from_variant("DbError", into)]
+    Wrapped(T),
+}
+
+#[test]
+fn bounded_fields_convert_through_the_bound() {
+    let BoundByParam::Wrapped(value) = BoundByParam::<Wrapped>::from(DbError(3));
+    assert_eq!(value, Wrapped(3));
+    let BoundByWhere::Wrapped(value) = BoundByWhere::<Wrapped>::from(DbError(4));
+    assert_eq!(value, Wrapped(4));
+}
@@ -0,0 +1,25 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub enum LookupError {
+    Missing,
+    Denied { user: String },
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(group("LookupError") { Missing => NotFound, Denied => Forbidden })]
+pub enum ApiError {
+    NotFound,
+    Forbidden,
+}
+
+#[test]
+fn group_routes_each_source_variant() {
+    assert!(matches!(ApiError::from(LookupError::Missing), ApiError::NotFound));
+    assert!(matches!(
+        ApiError::from(LookupError::Denied {
+            user: "guest".to_string()
+        }),
+        ApiError::Forbidden
+    ));
+}
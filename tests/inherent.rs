@@ -0,0 +1,46 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("DbError", accessors, result)]
+    Database(DbError),
+    #[enum_from_variant("u16")]
+    Code(u16),
+}
+
+// A hand-written impl block sits next to the generated conversions and inherent methods.
+impl MainError {
+    pub fn new() -> Self {
+        MainError::Code(0)
+    }
+
+    pub fn code(&self) -> Option<u16> {
+        match self {
+            MainError::Code(code) => Some(*code),
+            MainError::Database(_) => None,
+        }
+    }
+}
+
+impl Default for MainError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn manual_impls_coexist_with_generated_ones() {
+    assert_eq!(MainError::new().code(), Some(0));
+    assert_eq!(MainError::from(7u16).code(), Some(7));
+    let err = MainError::from(DbError);
+    assert!(err.is_database());
+    assert!(err.as_database().is_some());
+    assert!(matches!(
+        MainError::err_database::<()>(DbError),
+        Err(MainError::Database(DbError))
+    ));
+    assert!(matches!(MainError::default(), MainError::Code(0)));
+}
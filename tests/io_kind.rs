@@ -0,0 +1,20 @@
+use enum_from_variant::EnumFromVariant;
+use std::io;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(io_kind { NotFound => Missing, PermissionDenied => Denied, _ => Io })]
+pub enum FileError {
+    Missing,
+    Denied(String),
+    Io(io::Error),
+}
+
+#[test]
+fn io_kind_routes_by_error_kind() {
+    let missing: FileError = io::Error::new(io::ErrorKind::NotFound, "no such file").into();
+    let denied: FileError = io::Error::new(io::ErrorKind::PermissionDenied, "read only").into();
+    let other: FileError = io::Error::new(io::ErrorKind::Interrupted, "try again").into();
+    assert!(matches!(missing, FileError::Missing));
+    assert!(matches!(denied, FileError::Denied(msg) if msg == "read only"));
+    assert!(matches!(other, FileError::Io(err) if err.kind() == io::ErrorKind::Interrupted));
+}
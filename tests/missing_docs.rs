@@ -0,0 +1,32 @@
+//! Generated public items must be documented for downstream crates that deny `missing_docs`.
+#![deny(missing_docs)]
+
+use enum_from_variant::EnumFromVariant;
+
+/// A database failure.
+#[derive(Debug)]
+pub struct DbError;
+
+/// Errors of the service.
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    /// The database failed.
+    #[enum_from_variant("DbError", accessors)]
+    Database(DbError),
+    /// The database failed while migrating.
+    #[enum_from_variant("Result<_, u8>", err)]
+    Migration(u8),
+    /// A value that doesn't fit.
+    #[enum_from_variant("u32", ext)]
+    Overflow(u32),
+}
+
+#[test]
+fn documented_items_are_generated() {
+    assert!(MainError::from(DbError).is_database());
+    assert!(matches!(
+        MainError::from_u8_result(Err::<(), _>(3)),
+        Err(MainError::Migration(3))
+    ));
+    assert!(matches!(MainError::from_u32(7), MainError::Overflow(7)));
+}
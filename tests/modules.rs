@@ -0,0 +1,101 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+mod errors {
+    use super::*;
+    use enum_from_variant::EnumFromVariant;
+
+    #[derive(Debug, EnumFromVariant)]
+    pub enum MainError {
+        #[enum_from_variant("DbError")]
+        Database(DbError),
+        #[enum_from_variant("NetworkError")]
+        Network(String),
+    }
+}
+
+// Source types are resolved where the enum is declared, so a qualified path works without any import.
+mod qualified {
+    use enum_from_variant::EnumFromVariant;
+
+    #[derive(Debug, EnumFromVariant)]
+    pub enum MainError {
+        #[enum_from_variant("super::DbError")]
+        Database(super::DbError),
+        #[enum_from_variant("crate::NetworkError")]
+        Network(String),
+    }
+}
+
+#[test]
+fn glob_imported_sources_resolve() {
+    assert!(matches!(
+        errors::MainError::from(DbError),
+        errors::MainError::Database(DbError)
+    ));
+    assert!(matches!(errors::MainError::from(NetworkError), errors::MainError::Network(msg) if msg == "network"));
+}
+
+#[test]
+fn qualified_sources_resolve() {
+    assert!(matches!(
+        qualified::MainError::from(DbError),
+        qualified::MainError::Database(DbError)
+    ));
+    assert!(matches!(qualified::MainError::from(NetworkError), qualified::MainError::Network(msg) if msg == "network"));
+}
+
+mod grouped {
+    use super::*;
+    use enum_from_variant::EnumFromVariant;
+
+    #[derive(Debug, EnumFromVariant)]
+    #[enum_from_variant(module = "conversions")]
+    pub enum MainError {
+        #[enum_from_variant("DbError", accessors)]
+        Database(DbError),
+        #[enum_from_variant("NetworkError", ext)]
+        Network(String),
+    }
+}
+
+#[test]
+fn module_impls_apply_outside_the_module() {
+    use grouped::MainErrorFromExt;
+
+    assert!(grouped::MainError::from(DbError).is_database());
+    assert!(
+        matches!(grouped::MainError::from_network_error(NetworkError), grouped::MainError::Network(msg) if msg == "network")
+    );
+}
+
+mod private {
+    use super::*;
+    use enum_from_variant::EnumFromVariant;
+
+    #[derive(Debug, EnumFromVariant)]
+    #[enum_from_variant(module = "conversions")]
+    enum PrivateError {
+        #[enum_from_variant("DbError", accessors, result)]
+        Database(DbError),
+    }
+
+    // The methods are private to this module, like the enum, rather than to `conversions`.
+    #[test]
+    fn module_keeps_methods_visible_to_the_enum_module() {
+        assert!(PrivateError::from(DbError).is_database());
+        assert!(PrivateError::from(DbError).as_database().is_some());
+        assert!(PrivateError::err_database::<()>(DbError).is_err());
+    }
+}
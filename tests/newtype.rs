@@ -0,0 +1,47 @@
+use enum_from_variant::enum_conversions;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+// `IpAddr` belongs to `std`, so this crate can only implement conversions for a local wrapper around it, which the
+// macro declares.
+enum_conversions! {
+    /// An address converted from raw octets.
+    #[derive(Debug, PartialEq)]
+    pub newtype Address(IpAddr),
+    [u8; 4] => (Address(IpAddr::V4)),
+    [u16; 8] => (Address(IpAddr::V6)),
+}
+
+mod private {
+    use enum_from_variant::enum_conversions;
+    use std::net::IpAddr;
+
+    enum_conversions! {
+        newtype Loopback(IpAddr),
+        std::net::Ipv4Addr => (Loopback(IpAddr::V4)),
+    }
+
+    #[test]
+    fn newtype_without_visibility_is_private() {
+        let Loopback(address) = Loopback::from(std::net::Ipv4Addr::LOCALHOST);
+        assert!(address.is_loopback());
+    }
+}
+
+fn parse_octets(octets: [u8; 4]) -> Result<(), Address> {
+    Err(octets)?
+}
+
+#[test]
+fn wrapper_converts_into_a_foreign_enum() {
+    assert_eq!(Address::from([127, 0, 0, 1]), Address(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    assert_eq!(
+        Address::from([0, 0, 0, 0, 0, 0, 0, 1]),
+        Address(IpAddr::V6(Ipv6Addr::LOCALHOST))
+    );
+    assert_eq!(
+        parse_octets([10, 0, 0, 1]),
+        Err(Address(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))))
+    );
+    let Address(inner) = Address::from([192, 168, 0, 1]);
+    assert!(inner.is_ipv4());
+}
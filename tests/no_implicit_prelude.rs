@@ -0,0 +1,115 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+#[derive(Debug)]
+pub struct DbError;
+
+pub mod bare {
+    #![no_implicit_prelude]
+
+    use super::{DbError, NetworkError};
+    use ::enum_from_variant::EnumFromVariant;
+    use ::std::boxed::Box;
+    use ::std::string::String;
+    use ::std::vec::Vec;
+
+    #[derive(::std::fmt::Debug, EnumFromVariant)]
+    #[enum_from_variant(io_kind { NotFound => Missing, _ => Io })]
+    pub enum BareError {
+        #[enum_from_variant("NetworkError", accessors, also_boxed)]
+        Network(String),
+        #[enum_from_variant("u32", also_str)]
+        Message(String),
+        #[enum_from_variant("DbError", boxed, result)]
+        Database(Box<DbError>),
+        #[enum_from_variant("u64", try)]
+        Small(u8),
+        #[enum_from_variant("::std::result::Result<i64, NetworkError>", err)]
+        Failed(String),
+        #[enum_from_variant("u16", collect)]
+        Codes(Vec<u16>),
+        #[enum_from_variant("char", prefix = "char: ")]
+        Char(String),
+        #[enum_from_variant("i8", debug_string)]
+        Debugged(String),
+        #[enum_from_variant("f32", chain)]
+        Chained {
+            message: String,
+            source: Box<f32>,
+        },
+        #[enum_from_variant("()")]
+        Cancelled,
+        Missing,
+        Io(::std::io::Error),
+    }
+
+    #[derive(::std::fmt::Debug)]
+    pub enum StorageError {
+        Database(DbError),
+        Busy,
+    }
+
+    #[derive(::std::fmt::Debug, EnumFromVariant)]
+    #[enum_from_variant(flatten(Storage) { "DbError" => Database })]
+    #[enum_from_variant(group("StorageError") { Database => Unavailable, Busy => Unavailable })]
+    pub enum RoutedError {
+        Storage(StorageError),
+        Unavailable,
+        #[enum_from_variant("u8", ext, count, into)]
+        Code(u32),
+    }
+
+    #[derive(::std::fmt::Debug, EnumFromVariant)]
+    #[enum_from_variant(display_delegate)]
+    pub enum BlanketError {
+        #[enum_from_variant(blanket)]
+        Message(String),
+    }
+}
+
+pub use bare::{BareError, BlanketError, RoutedError, RoutedErrorFromExt, StorageError};
+
+#[test]
+fn generated_code_needs_no_prelude() {
+    let err = BareError::from(NetworkError);
+    assert_eq!(err.as_network().map(String::as_str), Some("network"));
+    assert!(matches!(BareError::from(Box::new(NetworkError)), BareError::Network(_)));
+    assert!(matches!(BareError::from("msg"), BareError::Message(msg) if msg == "msg"));
+    assert!(matches!(
+        BareError::err_database::<()>(DbError),
+        Err(BareError::Database(_))
+    ));
+    assert!(BareError::try_from(300u64).is_err());
+    assert!(matches!(BareError::from(7u16), BareError::Codes(codes) if codes == [7]));
+    assert!(matches!(BareError::from('x'), BareError::Char(msg) if msg == "char: x"));
+    assert!(matches!(BareError::from(-1i8), BareError::Debugged(msg) if msg == "-1"));
+    assert!(matches!(BareError::from(1.5f32), BareError::Chained { message, .. } if message == "1.5"));
+    assert!(matches!(BareError::from(()), BareError::Cancelled));
+    let io = std::io::Error::from(std::io::ErrorKind::NotFound);
+    assert!(matches!(BareError::from(io), BareError::Missing));
+    assert!(matches!(
+        RoutedError::from(DbError),
+        RoutedError::Storage(StorageError::Database(_))
+    ));
+    assert!(matches!(
+        RoutedError::from(StorageError::Busy),
+        RoutedError::Unavailable
+    ));
+    assert!(matches!(RoutedError::from_u8(3), RoutedError::Code(3)));
+    assert_eq!(
+        RoutedError::from_u8_count().load(std::sync::atomic::Ordering::Relaxed),
+        1
+    );
+    assert!(matches!(BlanketError::from("msg"), BlanketError::Message(msg) if msg == "msg"));
+    assert_eq!(BlanketError::from('m').to_string(), "m");
+}
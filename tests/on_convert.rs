@@ -0,0 +1,59 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CONVERSIONS: AtomicUsize = AtomicUsize::new(0);
+
+mod hooks {
+    use super::*;
+
+    pub fn record<T: fmt::Debug>(_err: &T) {
+        CONVERSIONS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("DbError", on_convert = "hooks::record")]
+    Database(DbError),
+    #[enum_from_variant("NetworkError", on_convert = "hooks::record")]
+    Network(String),
+}
+
+#[test]
+fn on_convert_hook_runs_for_every_conversion() {
+    let before = CONVERSIONS.load(Ordering::SeqCst);
+    let _: MainError = DbError.into();
+    let network: MainError = NetworkError.into();
+    assert_eq!(CONVERSIONS.load(Ordering::SeqCst), before + 2);
+    assert!(matches!(network, MainError::Network(msg) if msg == "network"));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum CountedError {
+    #[enum_from_variant("DbError", count)]
+    Database(DbError),
+    #[enum_from_variant("NetworkError", count)]
+    Network(String),
+}
+
+#[test]
+fn count_tracks_conversions_per_source() {
+    let _: CountedError = DbError.into();
+    let _: CountedError = DbError.into();
+    let _: CountedError = NetworkError.into();
+    assert_eq!(CountedError::from_db_error_count().load(Ordering::Relaxed), 2);
+    assert_eq!(CountedError::from_network_error_count().load(Ordering::Relaxed), 1);
+}
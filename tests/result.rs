@@ -0,0 +1,55 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, PartialEq)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("Result<_, DbError>", err)]
+    Database(DbError),
+}
+
+fn query(ok: bool) -> Result<i32, DbError> {
+    if ok {
+        Ok(42)
+    } else {
+        Err(DbError)
+    }
+}
+
+#[test]
+fn err_maps_the_error_arm() {
+    assert!(matches!(
+        MainError::from_db_error_result(query(false)),
+        Err(MainError::Database(DbError))
+    ));
+}
+
+#[test]
+fn err_keeps_the_ok_value() {
+    assert!(matches!(MainError::from_db_error_result(query(true)), Ok(42)));
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PoolError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum StoreError {
+    #[enum_from_variant("PoolError", result)]
+    Pool(PoolError),
+}
+
+fn checkout(available: bool) -> Result<u8, StoreError> {
+    if available {
+        Ok(1)
+    } else {
+        StoreError::err_pool(PoolError)
+    }
+}
+
+#[test]
+fn result_helper_returns_the_conversion_as_err() {
+    assert!(matches!(checkout(false), Err(StoreError::Pool(PoolError))));
+    assert!(matches!(checkout(true), Ok(1)));
+    assert!(matches!(StoreError::from(PoolError), StoreError::Pool(PoolError)));
+}
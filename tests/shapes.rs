@@ -0,0 +1,80 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct QueryError;
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "query")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MixedError {
+    #[enum_from_variant("NetworkError")]
+    Network(String),
+    #[enum_from_variant("DbError")]
+    Database { source: DbError },
+    #[enum_from_variant("QueryError")]
+    Query {
+        source: String,
+        #[enum_from_variant(default)]
+        retries: u32,
+    },
+    #[enum_from_variant("()")]
+    Unknown,
+}
+
+#[test]
+fn every_variant_shape_converts() {
+    assert!(matches!(MixedError::from(NetworkError), MixedError::Network(msg) if msg == "network"));
+    assert!(matches!(
+        MixedError::from(DbError),
+        MixedError::Database { source: DbError }
+    ));
+    assert!(matches!(
+        MixedError::from(QueryError),
+        MixedError::Query { source, retries: 0 } if source == "query"
+    ));
+    assert!(matches!(MixedError::from(()), MixedError::Unknown));
+}
+
+// The derive leaves C-like enums without conversions alone.
+#[derive(Debug, Clone, Copy, PartialEq, EnumFromVariant)]
+#[repr(u8)]
+pub enum Code {
+    Ok = 0,
+    Failed = 1,
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[repr(u8)]
+pub enum ReprError {
+    #[enum_from_variant("()")]
+    Unknown = 0,
+    #[enum_from_variant("DbError")]
+    Database(DbError) = 1,
+    #[enum_from_variant("Code")]
+    Status(Code) = 2,
+}
+
+#[test]
+fn repr_enums_convert_into_their_data_variants() {
+    assert_eq!(Code::Failed as u8, 1);
+    assert!(matches!(ReprError::from(DbError), ReprError::Database(DbError)));
+    assert!(matches!(ReprError::from(Code::Ok), ReprError::Status(Code::Ok)));
+    assert!(matches!(ReprError::from(()), ReprError::Unknown));
+}
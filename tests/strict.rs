@@ -0,0 +1,112 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(strict)]
+pub enum MainError {
+    #[enum_from_variant("DbError", move)]
+    Database(DbError),
+    #[enum_from_variant("NetworkError", stringify)]
+    Network(String),
+    #[enum_from_variant("u16", into)]
+    Code(u32),
+    #[enum_from_variant("i32", via = "err.unsigned_abs()")]
+    Offset(u32),
+}
+
+#[test]
+fn strict_conversions_use_the_explicit_mode() {
+    assert!(matches!(MainError::from(DbError), MainError::Database(DbError)));
+    assert!(matches!(MainError::from(NetworkError), MainError::Network(msg) if msg == "network"));
+    assert!(matches!(MainError::from(7u16), MainError::Code(7)));
+    assert!(matches!(MainError::from(-3i32), MainError::Offset(3)));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Lenient {
+    // A mode overrides the guess without `strict` too, which would move the `String` into the `Box<str>` as is.
+    #[enum_from_variant("String", into)]
+    Message(Box<str>),
+}
+
+#[test]
+fn mode_overrides_guess_without_strict() {
+    assert!(matches!(Lenient::from(String::from("boxed")), Lenient::Message(msg) if &*msg == "boxed"));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MovedString {
+    #[enum_from_variant("String", move)]
+    Message(String),
+}
+
+#[test]
+fn move_keeps_the_string_buffer() {
+    let message = String::from("moved");
+    let buffer = message.as_ptr();
+    let MovedString::Message(moved) = message.into();
+    assert_eq!(moved.as_ptr(), buffer);
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(strict)]
+pub enum Truncated {
+    #[enum_from_variant("u32", cast)]
+    Code(u8),
+    #[enum_from_variant("f64", cast)]
+    Ratio { source: f32 },
+}
+
+#[test]
+fn cast_converts_with_as() {
+    assert!(matches!(Truncated::from(300u32), Truncated::Code(44)));
+    assert!(matches!(Truncated::from(0.5f64), Truncated::Ratio { source } if source == 0.5));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BorrowedSource {
+    #[enum_from_variant("&std::path::Path", to_owned)]
+    File(std::path::PathBuf),
+    #[enum_from_variant("&[u8]", to_owned)]
+    Bytes(Vec<u8>),
+    #[enum_from_variant("&str", to_owned)]
+    Name(String),
+}
+
+#[test]
+fn to_owned_clones_borrowed_sources() {
+    let err = BorrowedSource::from(std::path::Path::new("/tmp/config"));
+    assert!(matches!(err, BorrowedSource::File(path) if path == std::path::Path::new("/tmp/config")));
+    assert!(matches!(BorrowedSource::from(&[1u8, 2][..]), BorrowedSource::Bytes(bytes) if bytes == [1, 2]));
+    assert!(matches!(BorrowedSource::from("db"), BorrowedSource::Name(name) if name == "db"));
+}
+
+#[derive(Debug)]
+pub struct DebugOnly {
+    pub code: u8,
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Formatted {
+    #[enum_from_variant("DebugOnly", debug_string)]
+    Message(String),
+}
+
+#[test]
+fn debug_string_formats_debug_only_sources() {
+    assert!(
+        matches!(Formatted::from(DebugOnly { code: 4 }), Formatted::Message(msg) if msg == "DebugOnly { code: 4 }")
+    );
+}
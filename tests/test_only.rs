@@ -0,0 +1,34 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct MockError;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("MockError", test_only)]
+    Mock(MockError),
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+}
+
+// Outside of tests the generated conversion is gone, so this impl doesn't conflict with it.
+#[cfg(not(test))]
+impl From<MockError> for MainError {
+    fn from(_: MockError) -> MainError {
+        MainError::Database(DbError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_conversion_exists_under_test() {
+        assert!(matches!(MainError::from(MockError), MainError::Mock(MockError)));
+        assert!(matches!(MainError::from(DbError), MainError::Database(DbError)));
+    }
+}
@@ -0,0 +1,31 @@
+use enum_from_variant::EnumFromVariant;
+use std::time::SystemTime;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum AuditError {
+    #[enum_from_variant("DbError", timestamp)]
+    Database { source: DbError, when: SystemTime },
+    #[enum_from_variant("u16", timestamp, backtrace)]
+    Code {
+        source: u16,
+        timestamp: SystemTime,
+        backtrace: std::backtrace::Backtrace,
+    },
+}
+
+#[test]
+fn timestamp_is_taken_on_conversion() {
+    let before = SystemTime::now();
+    let AuditError::Database { source: DbError, when } = AuditError::from(DbError) else {
+        panic!("expected `AuditError::Database`");
+    };
+    assert!(when >= before && when <= SystemTime::now());
+    let AuditError::Code { source, timestamp, .. } = AuditError::from(404u16) else {
+        panic!("expected `AuditError::Code`");
+    };
+    assert_eq!(source, 404);
+    assert!(timestamp >= when);
+}
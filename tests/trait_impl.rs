@@ -0,0 +1,89 @@
+use enum_from_variant::EnumFromVariant;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct DbError;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "network")
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum StoreError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    #[enum_from_variant("NetworkError")]
+    Network(String),
+    #[enum_from_variant("std::num::ParseIntError")]
+    Parse(std::num::ParseIntError),
+}
+
+pub trait Store {
+    fn save(&mut self, key: &str, value: &str) -> Result<(), StoreError>;
+}
+
+#[derive(Default)]
+pub struct Remote {
+    db_down: bool,
+    network_down: bool,
+    saved: Vec<(String, u32)>,
+}
+
+impl Remote {
+    fn connect(&self) -> Result<(), NetworkError> {
+        if self.network_down {
+            Err(NetworkError)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn insert(&mut self, key: &str, value: u32) -> Result<(), DbError> {
+        if self.db_down {
+            return Err(DbError);
+        }
+        self.saved.push((key.to_string(), value));
+        Ok(())
+    }
+}
+
+impl Store for Remote {
+    fn save(&mut self, key: &str, value: &str) -> Result<(), StoreError> {
+        self.connect()?;
+        let value = value.parse()?;
+        self.insert(key, value)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn question_mark_converts_in_trait_methods() {
+    let mut remote = Remote::default();
+    remote.save("answer", "42").unwrap();
+    assert_eq!(remote.saved, [("answer".to_string(), 42)]);
+    assert!(matches!(remote.save("answer", "forty-two"), Err(StoreError::Parse(_))));
+
+    remote.db_down = true;
+    assert!(matches!(
+        remote.save("answer", "42"),
+        Err(StoreError::Database(DbError))
+    ));
+
+    remote.network_down = true;
+    assert!(matches!(remote.save("answer", "42"), Err(StoreError::Network(msg)) if msg == "network"));
+}
+
+// Through a trait object, the impl is only known at run time.
+#[test]
+fn question_mark_converts_through_trait_objects() {
+    let mut store: Box<dyn Store> = Box::new(Remote {
+        db_down: true,
+        ..Remote::default()
+    });
+    assert!(matches!(store.save("answer", "42"), Err(StoreError::Database(DbError))));
+}
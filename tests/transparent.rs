@@ -0,0 +1,20 @@
+use enum_from_variant::EnumFromVariant;
+use std::io;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(transparent, deref)]
+pub enum IoWrapper {
+    Io(io::Error),
+}
+
+#[test]
+fn transparent_converts_from_the_wrapped_type() {
+    let err: IoWrapper = io::Error::other("disk").into();
+    assert!(matches!(err, IoWrapper::Io(inner) if inner.to_string() == "disk"));
+}
+
+#[test]
+fn deref_reaches_the_wrapped_type() {
+    let err = IoWrapper::from(io::Error::new(io::ErrorKind::NotFound, "gone"));
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
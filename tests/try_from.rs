@@ -0,0 +1,209 @@
+use enum_from_variant::{ConversionError, EnumFromVariant};
+use std::convert::TryFrom;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum StatusError {
+    #[enum_from_variant("u32", try)]
+    Code(u8),
+}
+
+#[test]
+fn try_conversion_succeeds_when_value_fits() {
+    assert!(matches!(StatusError::try_from(42u32), Ok(StatusError::Code(42))));
+}
+
+#[test]
+fn try_conversion_error_names_source_and_target() {
+    let err = StatusError::try_from(300u32).unwrap_err();
+    assert_eq!(
+        err,
+        ConversionError {
+            source_type: "u32",
+            target_type: "StatusError::Code",
+        }
+    );
+    assert_eq!(err.to_string(), "cannot convert `u32` into `StatusError::Code`");
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PortError(String);
+
+impl From<std::num::TryFromIntError> for PortError {
+    fn from(err: std::num::TryFromIntError) -> Self {
+        PortError(err.to_string())
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ConfigError {
+    #[enum_from_variant("u32", try, error = "PortError")]
+    Port(u16),
+    #[enum_from_variant(
+        "&str",
+        try,
+        error = "PortError",
+        via = "err.parse().map(ConfigError::Host).map_err(|_| PortError(format!(\"bad host {}\", err)))"
+    )]
+    Host(u8),
+}
+
+#[test]
+fn custom_error_type_is_used_for_try_into_failures() {
+    assert!(matches!(ConfigError::try_from(8080u32), Ok(ConfigError::Port(8080))));
+    assert!(matches!(ConfigError::try_from(70_000u32), Err(PortError(_))));
+}
+
+#[test]
+fn custom_error_type_is_built_by_via() {
+    assert!(matches!(ConfigError::try_from("7"), Ok(ConfigError::Host(7))));
+    assert_eq!(
+        ConfigError::try_from("x").unwrap_err(),
+        PortError("bad host x".to_string())
+    );
+}
+
+mod reexported {
+    pub use enum_from_variant as errors;
+}
+
+#[derive(Debug, enum_from_variant::EnumFromVariant)]
+#[enum_from_variant(crate = "reexported::errors")]
+pub enum AliasedError {
+    #[enum_from_variant("u32", try)]
+    Code(u8),
+}
+
+#[test]
+fn crate_path_reaches_conversion_error() {
+    let err: reexported::errors::ConversionError = AliasedError::try_from(300u32).unwrap_err();
+    assert_eq!(err.target_type, "AliasedError::Code");
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Value(u32);
+
+#[derive(Debug, PartialEq)]
+pub struct ParseFailure;
+
+#[derive(Debug, PartialEq)]
+pub struct ReadFailure(ParseFailure);
+
+impl From<ParseFailure> for ReadFailure {
+    fn from(err: ParseFailure) -> Self {
+        ReadFailure(err)
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Parsed {
+    #[enum_from_variant("Result<Value, ParseFailure>", ok)]
+    Value(Value),
+    #[enum_from_variant("Result<u8, ParseFailure>", ok, error = "ReadFailure")]
+    Byte(u8),
+}
+
+#[test]
+fn ok_builds_the_variant_from_the_ok_value() {
+    assert!(matches!(Parsed::try_from(Ok(Value(3))), Ok(Parsed::Value(Value(3)))));
+    assert!(matches!(Parsed::try_from(Ok::<u8, _>(9)), Ok(Parsed::Byte(9))));
+}
+
+#[test]
+fn ok_fails_on_the_error() {
+    let err = Parsed::try_from(Err::<Value, _>(ParseFailure)).unwrap_err();
+    assert_eq!(err.target_type, "Parsed::Value");
+    assert_eq!(
+        Parsed::try_from(Err::<u8, _>(ParseFailure)).unwrap_err(),
+        ReadFailure(ParseFailure)
+    );
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RawConfig {
+    port: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    port: u16,
+}
+
+impl TryFrom<RawConfig> for Config {
+    type Error = ConversionError;
+
+    fn try_from(raw: RawConfig) -> Result<Self, Self::Error> {
+        let port = raw.port.try_into().map_err(|_| ConversionError {
+            source_type: "RawConfig",
+            target_type: "Config",
+        })?;
+        Ok(Config { port })
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum SetupError {
+    #[enum_from_variant("RawConfig", try, via = "|r| Ok(Self::Config(r.try_into()?))")]
+    Config(Config),
+}
+
+#[test]
+fn try_via_closure_is_the_whole_conversion() {
+    assert!(matches!(
+        SetupError::try_from(RawConfig { port: 80 }),
+        Ok(SetupError::Config(Config { port: 80 }))
+    ));
+    assert_eq!(
+        SetupError::try_from(RawConfig { port: 70_000 })
+            .unwrap_err()
+            .target_type,
+        "Config"
+    );
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum KeyError {
+    #[enum_from_variant("Vec<u8>", try, keep_source)]
+    Key([u8; 4]),
+    #[enum_from_variant("i64", try, keep_source)]
+    Index(u8),
+}
+
+#[test]
+fn keep_source_hands_the_source_back_on_failure() {
+    assert!(matches!(
+        KeyError::try_from(vec![1, 2, 3, 4]),
+        Ok(KeyError::Key([1, 2, 3, 4]))
+    ));
+    let (source, err) = KeyError::try_from(vec![1, 2]).unwrap_err();
+    assert_eq!(source, [1, 2]);
+    assert_eq!(err.target_type, "KeyError::Key");
+    let (source, err) = KeyError::try_from(-1i64).unwrap_err();
+    assert_eq!(source, -1);
+    assert_eq!(err.source_type, "i64");
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum InputError {
+    #[enum_from_variant("String", try, when = "!err.is_empty()")]
+    Name(String),
+    #[enum_from_variant("i32", try, keep_source, when = "err % 2 == 0")]
+    Even(u8),
+}
+
+#[test]
+fn when_accepts_passing_sources() {
+    assert!(matches!(InputError::try_from("db".to_string()), Ok(InputError::Name(name)) if name == "db"));
+    assert!(matches!(InputError::try_from(4i32), Ok(InputError::Even(4))));
+}
+
+#[test]
+fn when_rejects_failing_sources() {
+    let err = InputError::try_from(String::new()).unwrap_err();
+    assert_eq!(err.source_type, "String");
+    assert_eq!(err.target_type, "InputError::Name");
+    let (source, err) = InputError::try_from(3i32).unwrap_err();
+    assert_eq!(source, 3);
+    assert_eq!(err.target_type, "InputError::Even");
+    // A passing source can still fail the conversion itself.
+    assert!(InputError::try_from(300i32).is_err());
+}
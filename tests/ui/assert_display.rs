@@ -0,0 +1,12 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("NetworkError", assert_display)]
+    Network(String),
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+// `manual` promises a hand-written impl, which `assert_impls` finds missing.
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(assert_impls)]
+pub enum MainError {
+    #[enum_from_variant("DbError", manual)]
+    Database(DbError),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DatabaseError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from(Db <- DatabaseError)]
+pub enum MainError {
+    Database(DatabaseError),
+}
+
+fn main() {}
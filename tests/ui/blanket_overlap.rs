@@ -0,0 +1,11 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant(blanket)]
+    Message(String),
+    #[enum_from_variant(blanket)]
+    Code(u32),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant(blanket)]
+    Message(String),
+    #[enum_from_variant("String")]
+    Owned(String),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant(blanket)]
+    Message(String),
+    #[enum_from_variant("&'static str")]
+    Borrowed(String),
+    #[enum_from_variant("char")]
+    Letter(char),
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use enum_from_variant::EnumFromVariant;
+use std::error::Error;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct LocalError(Rc<()>);
+
+impl fmt::Display for LocalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "local")
+    }
+}
+
+impl Error for LocalError {}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("LocalError", boxed_dyn)]
+    Dyn(Box<dyn Error + Send + Sync + 'static>),
+}
+
+fn main() {}
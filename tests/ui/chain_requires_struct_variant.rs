@@ -0,0 +1,12 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct ThirdPartyError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("ThirdPartyError", chain)]
+    External(String),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct SubError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum BatchError {
+    #[enum_from_variant("SubError", collect)]
+    Error(SubError),
+}
+
+fn main() {}
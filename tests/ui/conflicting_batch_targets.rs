@@ -0,0 +1,21 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from(Database <- DbError, Other <- DbError)]
+pub enum MainError {
+    Database(DbError),
+    Other(DbError),
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from(Other <- DbError)]
+pub enum StoreError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    Other(DbError),
+}
+
+fn main() {}
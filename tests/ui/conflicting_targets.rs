@@ -0,0 +1,14 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(table(("DbError", Database)))]
+#[enum_from_variant(box_all("DbError") => Other)]
+pub enum MainError {
+    Database(DbError),
+    Other(Box<dyn std::fmt::Debug>),
+}
+
+fn main() {}
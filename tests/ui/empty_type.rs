@@ -0,0 +1,9 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("")]
+    Network(String),
+}
+
+fn main() {}
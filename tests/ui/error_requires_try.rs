@@ -0,0 +1,12 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct PortError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ConfigError {
+    #[enum_from_variant("u16", error = "PortError")]
+    Port(u16),
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("DbError")]
+    Cancelled,
+    #[enum_from_variant("u8")]
+    Empty(),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(io_kind { NotFound => Missing })]
+pub enum FileError {
+    Missing,
+}
+
+fn main() {}
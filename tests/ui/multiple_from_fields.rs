@@ -0,0 +1,13 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    Io {
+        #[from]
+        source: std::io::Error,
+        #[from]
+        path: String,
+    },
+}
+
+fn main() {}
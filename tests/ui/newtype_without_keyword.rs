@@ -0,0 +1,7 @@
+use enum_from_variant::enum_conversions;
+
+enum_conversions! {
+    pub Address(std::net::IpAddr),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant(42)]
+    Code(u32),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(strict)]
+pub enum MainError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+}
+
+fn main() {}
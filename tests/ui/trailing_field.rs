@@ -0,0 +1,12 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct SourceErr;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum TaggedError {
+    #[enum_from_variant("SourceErr")]
+    Tagged(SourceErr, u32),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum StatusError {
+    #[enum_from_variant("u32", try, via = "Self::Code(err as u8)")]
+    Code(u8),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Bounds {
+    #[enum_from_variant("(u32, u32, u32)")]
+    Range(u32, u32),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use enum_from_variant::EnumFromVariant;
+
+pub trait Backend {
+    type Error;
+}
+
+#[derive(EnumFromVariant)]
+pub enum StoreError<T: Backend> {
+    #[enum_from_variant("T::Error")]
+    Backend(T::Error),
+}
+
+fn main() {}
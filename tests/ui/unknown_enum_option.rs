@@ -0,0 +1,27 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(stritc)]
+pub enum StrictError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(method_styel = "snake")]
+pub enum StyledError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+}
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(bound)]
+pub enum BoundError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+}
+
+fn main() {}
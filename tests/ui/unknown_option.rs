@@ -0,0 +1,15 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MainError {
+    #[enum_from_variant("u64", trie)]
+    Small(u8),
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum OtherError {
+    #[enum_from_variant("u64", try = "yes")]
+    Small(u8),
+}
+
+fn main() {}
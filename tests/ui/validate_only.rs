@@ -0,0 +1,13 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct NetworkError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(validate_only)]
+pub enum StagedError {
+    #[enum_from_variant("NetworkError", also_str)]
+    Network(NetworkError),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(validate_only)]
+#[enum_from(Replica <- DbError)]
+pub enum StagedError {
+    #[enum_from_variant("DbError")]
+    Database(DbError),
+    #[enum_from_variant("DbError")]
+    Primary(DbError),
+    Replica(DbError),
+}
+
+fn main() {}
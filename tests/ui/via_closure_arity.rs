@@ -0,0 +1,9 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum Range {
+    #[enum_from_variant("u32", via = "|start, end| start + end")]
+    Span(u32),
+}
+
+fn main() {}
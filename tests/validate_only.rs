@@ -0,0 +1,32 @@
+use enum_from_variant::EnumFromVariant;
+
+#[derive(Debug)]
+pub struct DbError;
+
+#[derive(Debug, EnumFromVariant)]
+#[enum_from_variant(validate_only)]
+pub enum StagedError {
+    #[enum_from_variant("DbError", accessors)]
+    Database(DbError),
+    #[enum_from_variant("u16")]
+    Code(u16),
+}
+
+// Nothing is generated, so these impls don't conflict with the declared conversions.
+impl From<DbError> for StagedError {
+    fn from(_: DbError) -> StagedError {
+        StagedError::Code(0)
+    }
+}
+
+impl From<u16> for StagedError {
+    fn from(code: u16) -> StagedError {
+        StagedError::Code(code + 1)
+    }
+}
+
+#[test]
+fn validate_only_generates_nothing() {
+    assert!(matches!(StagedError::from(DbError), StagedError::Code(0)));
+    assert!(matches!(StagedError::from(1u16), StagedError::Code(2)));
+}
@@ -0,0 +1,226 @@
+use enum_from_variant::EnumFromVariant;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ExitError {
+    #[enum_from_variant("i32", via = "err.abs()")]
+    Code(i32),
+    #[enum_from_variant("i8", via = "|code: i8| i64::from(code) * 2")]
+    Doubled(i64),
+    #[enum_from_variant("(u8, u8)", via = "|pair| Self::Signal { number: pair.0, core_dumped: pair.1 != 0 }")]
+    Signal { number: u8, core_dumped: bool },
+}
+
+#[test]
+fn via_expression_transforms_source() {
+    assert!(matches!(ExitError::from(-3), ExitError::Code(3)));
+}
+
+#[test]
+fn via_closure_is_called_with_source() {
+    assert!(matches!(ExitError::from(-4i8), ExitError::Doubled(-8)));
+}
+
+#[test]
+fn via_closure_building_the_enum_names_its_own_binding() {
+    assert!(matches!(
+        ExitError::from((9u8, 1u8)),
+        ExitError::Signal {
+            number: 9,
+            core_dumped: true
+        }
+    ));
+}
+
+#[derive(Debug)]
+pub struct LowError;
+
+#[derive(Debug, EnumFromVariant)]
+pub enum MidError {
+    #[enum_from_variant("LowError")]
+    Low(LowError),
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum TopError {
+    #[enum_from_variant("LowError", through = "MidError")]
+    Mid(MidError),
+}
+
+#[test]
+fn through_chains_conversions() {
+    assert!(matches!(
+        TopError::from(LowError),
+        TopError::Mid(MidError::Low(LowError))
+    ));
+}
+
+#[derive(Debug, Default)]
+pub struct State {
+    pub retries: u32,
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum SharedError {
+    // `via` expressions resolve names where the enum is declared, imports included.
+    #[enum_from_variant("State", via = "|state| Arc::new(Mutex::new(state))")]
+    Shared(Arc<Mutex<State>>),
+}
+
+#[test]
+fn via_closure_builds_nested_generic_constructors() {
+    let SharedError::Shared(state) = SharedError::from(State { retries: 2 });
+    state.lock().unwrap().retries += 1;
+    assert_eq!(state.lock().unwrap().retries, 3);
+}
+
+fn inspect(buffer: &[u8]) {
+    assert!(!buffer.is_empty());
+}
+
+// The source is moved into the wrapper, after a hook has only borrowed it.
+#[derive(Debug, EnumFromVariant)]
+pub enum LockedError {
+    #[enum_from_variant("Vec<u8>", via = "Mutex::new(err)", on_convert = "inspect")]
+    Buffer(Mutex<Vec<u8>>),
+    #[enum_from_variant("String", via = "|message| Mutex::new(message)")]
+    Message(Mutex<String>),
+}
+
+#[test]
+fn via_moves_non_copy_sources_into_wrappers() {
+    let buffer = vec![1u8, 2, 3];
+    let address = buffer.as_ptr();
+    let LockedError::Buffer(locked) = LockedError::from(buffer) else {
+        panic!("expected `LockedError::Buffer`");
+    };
+    assert_eq!(locked.lock().unwrap().as_ptr(), address);
+    let message = String::from("locked");
+    let address = message.as_ptr();
+    let LockedError::Message(locked) = LockedError::from(message) else {
+        panic!("expected `LockedError::Message`");
+    };
+    let message = locked.into_inner().unwrap();
+    assert_eq!(message.as_ptr(), address);
+}
+
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum RequestError {
+    #[enum_from_variant("HttpResponse", via = "Self::Http { status: err.status, body: err.body }")]
+    Http { status: u16, body: String },
+    #[enum_from_variant("u16", via = "RequestError::Status(err, None)")]
+    Status(u16, Option<String>),
+}
+
+#[test]
+fn via_can_build_the_whole_variant() {
+    let response = HttpResponse {
+        status: 503,
+        body: "unavailable".to_string(),
+    };
+    assert!(matches!(
+        RequestError::from(response),
+        RequestError::Http { status: 503, body } if body == "unavailable"
+    ));
+    assert!(matches!(RequestError::from(404u16), RequestError::Status(404, None)));
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ContextError {
+    #[enum_from_variant("std::io::Error", prefix = "io: ")]
+    Io(String),
+    #[enum_from_variant("std::fmt::Error", prefix = "{fmt} ")]
+    Fmt(String),
+}
+
+#[test]
+fn prefix_prepends_context() {
+    let err: ContextError = std::io::Error::other("disk full").into();
+    assert!(matches!(err, ContextError::Io(msg) if msg == "io: disk full"));
+    let err: ContextError = std::fmt::Error.into();
+    assert!(matches!(err, ContextError::Fmt(msg) if msg == "{fmt} an error occurred when formatting an argument"));
+}
+
+pub struct Location {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum ParseError {
+    #[enum_from_variant("Location", via = "|loc: Location| (loc.file, loc.line, loc.column)")]
+    At(String, u32, u32),
+    #[enum_from_variant("(u8, u8)", via = "(err.1, err.0)")]
+    Swapped(u8, u8),
+}
+
+#[test]
+fn via_tuple_is_spread_over_fields() {
+    let location = Location {
+        file: "main.rs".to_string(),
+        line: 3,
+        column: 14,
+    };
+    assert!(matches!(ParseError::from(location), ParseError::At(file, 3, 14) if file == "main.rs"));
+    assert!(matches!(ParseError::from((1u8, 2u8)), ParseError::Swapped(2, 1)));
+}
+
+#[derive(Debug)]
+pub struct Wrapper(u32);
+
+impl Wrapper {
+    pub fn into_inner(self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+pub struct Ticket(Wrapper);
+
+impl Ticket {
+    pub fn into_inner(self) -> Wrapper {
+        self.0
+    }
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum AccessorError {
+    #[enum_from_variant("Wrapper", via = "err.into_inner()")]
+    Code(u32),
+    #[enum_from_variant("Ticket", via = "err.into_inner().into_inner().to_string()")]
+    Label(String),
+}
+
+#[test]
+fn via_method_chains_reach_the_payload() {
+    assert!(matches!(AccessorError::from(Wrapper(7)), AccessorError::Code(7)));
+    assert!(matches!(AccessorError::from(Ticket(Wrapper(9))), AccessorError::Label(label) if label == "9"));
+}
+
+#[derive(Debug)]
+pub struct Snapshot {
+    pub frames: Vec<u8>,
+    pub label: &'static str,
+}
+
+#[derive(Debug, EnumFromVariant)]
+pub enum SnapshotError {
+    #[enum_from_variant("Snapshot", via = "err.frames.len()", via_ref)]
+    Size(usize),
+}
+
+#[test]
+fn via_ref_converts_from_a_borrowed_source() {
+    let snapshot = Snapshot {
+        frames: vec![1, 2, 3],
+        label: "boot",
+    };
+    assert!(matches!(SnapshotError::from(&snapshot), SnapshotError::Size(3)));
+    assert_eq!(snapshot.label, "boot");
+}